@@ -0,0 +1,19 @@
+/// Which way a step down the Farey tree goes: left to the smaller child, or right to the larger one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// To the smaller child, a `0` bit in the path
+    Left,
+    /// To the larger child, a `1` bit in the path
+    Right,
+}
+
+impl Direction {
+    /// The direction of a step stored as the bit `right`.
+    pub(crate) const fn from_bit(right: bool) -> Self {
+        if right {
+            Self::Right
+        } else {
+            Self::Left
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+/// Why a value couldn't be turned into a fraction.
+///
+/// Every constructor and conversion draws the same line between the two ways a value can fail to fit: a magnitude past the largest the type holds is `OutOfRange`, and a value in range that sits deeper in the Farey tree than the type goes is `PrecisionLoss`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FractionError {
+    /// The value is negative, and the fraction type is unsigned
+    Negative,
+    /// The value is `NaN`
+    NaN,
+    /// The value is infinite, or has a denominator of `0`
+    Infinite,
+    /// The value's magnitude is greater than `max`, the largest the fraction type holds
+    OutOfRange { max: u32 },
+    /// The value is deeper in the Farey tree than the fraction type goes; it would take `bits_needed` bits to hold exactly
+    PrecisionLoss { bits_needed: u128 },
+    /// The bits don't describe a node of the Farey tree
+    InvalidBitPattern,
+    /// The text isn't a fraction, mixed number, decimal or integer
+    InvalidSyntax,
+}
+
+impl Display for FractionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Negative => write!(f, "an unsigned fraction can't be negative"),
+            Self::NaN => write!(f, "`NaN` isn't a fraction"),
+            Self::Infinite => write!(f, "an infinite value isn't a fraction"),
+            Self::OutOfRange { max } => {
+                write!(
+                    f,
+                    "the value is out of range; the largest magnitude is {max}"
+                )
+            }
+            Self::PrecisionLoss { bits_needed } => {
+                write!(f, "the value needs {bits_needed} bits to be held exactly")
+            }
+            Self::InvalidBitPattern => write!(f, "the bits don't describe a fraction"),
+            Self::InvalidSyntax => write!(f, "the text isn't a number"),
+        }
+    }
+}
+
+impl Error for FractionError {}
+
+impl FractionError {
+    /// `NaN` or `Infinite` for a float that isn't finite.
+    pub(crate) const fn check_finite(value: f64) -> Result<(), Self> {
+        if value.is_nan() {
+            Err(Self::NaN)
+        } else if value.is_infinite() {
+            Err(Self::Infinite)
+        } else {
+            Ok(())
+        }
+    }
+}
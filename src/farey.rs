@@ -0,0 +1,528 @@
+use std::cmp::Ordering;
+
+use crate::{parse::Natural, wide::U256, FractionError};
+
+/// Compare `a/b` with `c/d` without multiplying, so that any pair of `U256` ratios can be compared exactly.
+///
+/// A zero denominator is treated as infinity. `0/0` must not be passed.
+pub const fn cmp_ratio(a: U256, b: U256, c: U256, d: U256) -> Ordering {
+    let (mut a, mut b, mut c, mut d) = (a, b, c, d);
+    let mut flipped = false;
+    loop {
+        let ordering = match (b.is_zero(), d.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => {
+                let (lhs_int, lhs_rem) = a.div_rem(b);
+                let (rhs_int, rhs_rem) = c.div_rem(d);
+                match lhs_int.cmp(rhs_int) {
+                    Ordering::Equal => {
+                        // the integer parts match, so compare the reciprocals of the remainders instead
+                        (a, b, c, d) = (b, lhs_rem, d, rhs_rem);
+                        flipped = !flipped;
+                        continue;
+                    }
+                    ordering => ordering,
+                }
+            }
+        };
+        return if flipped {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+    }
+}
+
+/// How many steps down the Farey tree `num/den` is, saturating at `u128::MAX`. The ratio needn't be in lowest terms, but neither part may be zero.
+pub const fn depth(num: U256, den: U256) -> u128 {
+    // each step of Euclid's algorithm is a run of steps in the same direction
+    let (mut num, mut den) = (num, den);
+    let mut steps: u128 = 0;
+    while !den.is_zero() {
+        let (quotient, remainder) = num.div_rem(den);
+        steps = match quotient.to_u128() {
+            Some(quotient) => steps.saturating_add(quotient),
+            None => u128::MAX,
+        };
+        (num, den) = (den, remainder);
+    }
+    steps - 1
+}
+
+/// A walk down the Farey tree, starting at `1/1`.
+///
+/// The path taken so far is stored in the low `depth` bits of `path`, first step first, with `1` for a step to the right.
+#[derive(Clone, Copy, Debug)]
+pub struct Descent {
+    lower_num: u128,
+    lower_denom: u128,
+    upper_num: u128,
+    upper_denom: u128,
+    path: u128,
+    depth: u32,
+    max_depth: u32,
+}
+
+impl Descent {
+    /// Start a walk at `1/1` that may go at most `max_depth` steps down the tree.
+    pub const fn new(max_depth: u32) -> Self {
+        Self {
+            lower_num: 0,
+            lower_denom: 1,
+            upper_num: 1,
+            upper_denom: 0,
+            path: 0,
+            depth: 0,
+            max_depth,
+        }
+    }
+
+    /// Whether the walk has gone as far down the tree as it may.
+    pub const fn is_at_max_depth(&self) -> bool {
+        self.depth == self.max_depth
+    }
+
+    /// How many more steps the walk may take.
+    pub const fn room(&self) -> u32 {
+        self.max_depth - self.depth
+    }
+
+    /// The numerator and denominator of the current node.
+    pub const fn node(&self) -> (u128, u128) {
+        (
+            self.lower_num + self.upper_num,
+            self.lower_denom + self.upper_denom,
+        )
+    }
+
+    /// Take `steps` steps in the same direction. The caller must not go past `max_depth`.
+    pub const fn step(&mut self, right: bool, steps: u32) {
+        let steps_wide = steps as u128;
+        if right {
+            self.lower_num += steps_wide * self.upper_num;
+            self.lower_denom += steps_wide * self.upper_denom;
+            self.path = (self.path << steps) | ((1 << steps) - 1);
+        } else {
+            self.upper_num += steps_wide * self.lower_num;
+            self.upper_denom += steps_wide * self.lower_denom;
+            self.path <<= steps;
+        }
+        self.depth += steps;
+    }
+
+    /// Walk towards `num/den`, given relative to the current node: a ratio greater than one lies in the right subtree, and a ratio of one is the current node itself.
+    ///
+    /// Stops on reaching the value or `max_depth`, and returns what is left of the ratio.
+    const fn descend(&mut self, mut num: U256, mut den: U256) -> (U256, U256) {
+        while self.depth < self.max_depth && !num.eq(den) {
+            let room = self.max_depth - self.depth;
+            let right = matches!(num.cmp(den), Ordering::Greater);
+            let (larger, smaller) = if right { (num, den) } else { (den, num) };
+            let (steps, _) = larger.wrapping_sub(U256::ONE).div_rem(smaller);
+            #[allow(clippy::cast_possible_truncation)]
+            let steps = match steps.to_u128() {
+                Some(steps) if steps < room as u128 => steps as u32,
+                _ => room,
+            };
+            let larger = larger.wrapping_sub(smaller.mul_u32(steps));
+            if right {
+                num = larger;
+            } else {
+                den = larger;
+            }
+            self.step(right, steps);
+        }
+        (num, den)
+    }
+
+    /// Round `num/den`, given relative to the current node, to the nearest node within `max_depth`, and return it in the `UFrac` bit layout.
+    ///
+    /// When the value is exactly halfway between two nodes, the one closer to the root wins. Values past the deepest node on the right edge of the tree round to that node.
+    pub const fn nearest(mut self, num: U256, den: U256) -> u128 {
+        if num.is_zero() {
+            return self.lower_bits();
+        }
+        let (num, den) = self.descend(num, den);
+        match num.cmp(den) {
+            Ordering::Equal => self.bits(),
+            // `self` lies between the current node and `upper`, which is closer when the ratio reaches `(lower_denom + 2 * upper_denom) / upper_denom`
+            Ordering::Greater => {
+                if self.upper_denom == 0 {
+                    return self.bits();
+                }
+                match cmp_ratio(
+                    num,
+                    den,
+                    U256::from_u128(self.lower_denom + 2 * self.upper_denom),
+                    U256::from_u128(self.upper_denom),
+                ) {
+                    Ordering::Less => self.bits(),
+                    Ordering::Equal | Ordering::Greater => self.upper_bits(),
+                }
+            }
+            // likewise, `lower` is closer when the ratio falls to `lower_denom / (2 * lower_denom + upper_denom)`
+            Ordering::Less => match cmp_ratio(
+                num,
+                den,
+                U256::from_u128(self.lower_denom),
+                U256::from_u128(2 * self.lower_denom + self.upper_denom),
+            ) {
+                Ordering::Greater => self.bits(),
+                Ordering::Equal | Ordering::Less => self.lower_bits(),
+            },
+        }
+    }
+
+    /// The node exactly equal to `num/den`, given relative to the current node, in the `UFrac` bit layout. Returns `None` if it's deeper than `max_depth`.
+    pub const fn exact(mut self, num: U256, den: U256) -> Option<u128> {
+        if num.is_zero() {
+            return Some(self.lower_bits());
+        }
+        let (num, den) = self.descend(num, den);
+        if num.eq(den) {
+            Some(self.bits())
+        } else {
+            None
+        }
+    }
+
+    /// Round `num/den`, given relative to the current node, down to the nearest node within `max_depth` that isn't greater than it, in the `UFrac` bit layout.
+    pub const fn floor(mut self, num: U256, den: U256) -> u128 {
+        if num.is_zero() {
+            return self.lower_bits();
+        }
+        let (num, den) = self.descend(num, den);
+        match num.cmp(den) {
+            Ordering::Equal | Ordering::Greater => self.bits(),
+            Ordering::Less => self.lower_bits(),
+        }
+    }
+
+    /// Round `num/den`, given relative to the current node, up to the nearest node within `max_depth` that isn't less than it, in the `UFrac` bit layout. Returns `None` if it's past the deepest node on the right edge of the tree.
+    pub const fn ceil(mut self, num: U256, den: U256) -> Option<u128> {
+        if num.is_zero() {
+            return Some(self.lower_bits());
+        }
+        let (num, den) = self.descend(num, den);
+        match num.cmp(den) {
+            Ordering::Equal | Ordering::Less => Some(self.bits()),
+            Ordering::Greater if self.upper_denom == 0 => None,
+            Ordering::Greater => Some(self.upper_bits()),
+        }
+    }
+
+    /// Round `num/den`, given relative to the current node, to the nearest node within `max_depth`, in the `UFrac` bit layout. Returns `None` if it's past the deepest node on the right edge of the tree.
+    pub const fn checked(self, num: U256, den: U256) -> Option<u128> {
+        let mut descent = self;
+        if !num.is_zero() {
+            let (num, den) = descent.descend(num, den);
+            if descent.upper_denom == 0 && matches!(num.cmp(den), Ordering::Greater) {
+                return None;
+            }
+        }
+        Some(self.nearest(num, den))
+    }
+
+    /// The current node in the `UFrac` bit layout.
+    pub const fn bits(&self) -> u128 {
+        Self::node_bits(self.path, self.depth, self.max_depth)
+    }
+
+    /// The nearest ancestor to the left of the current node in the `UFrac` bit layout, or `0` if there is none.
+    const fn lower_bits(&self) -> u128 {
+        if self.path == 0 {
+            0
+        } else {
+            let steps_up = self.path.trailing_zeros() + 1;
+            Self::node_bits(self.path >> steps_up, self.depth - steps_up, self.max_depth)
+        }
+    }
+
+    /// The nearest ancestor to the right of the current node in the `UFrac` bit layout. Must not be called on the right edge of the tree.
+    const fn upper_bits(&self) -> u128 {
+        let steps_up = self.path.trailing_ones() + 1;
+        Self::node_bits(self.path >> steps_up, self.depth - steps_up, self.max_depth)
+    }
+
+    /// Lay out a path MSB-first, followed by a terminating `1` bit, in `max_depth + 1` bits.
+    const fn node_bits(path: u128, depth: u32, max_depth: u32) -> u128 {
+        ((path << 1) | 1) << (max_depth - depth)
+    }
+}
+
+/// Move a node from the `UFrac` bit layout with room for `max_depth` steps, where the path runs from the most significant bit down, to the magnitude bits of the `IFrac` layout, where it runs from the least significant bit up.
+///
+/// Returns the magnitude along with the node's depth.
+pub const fn ufrac_to_ifrac_bits(bits: u128, max_depth: u32) -> (u128, u32) {
+    if bits == 0 {
+        return (0, 0);
+    }
+    let trailing_zeros = bits.trailing_zeros();
+    let depth = max_depth - trailing_zeros;
+    let path = match bits.checked_shr(trailing_zeros + 1) {
+        Some(path) => path,
+        None => 0,
+    };
+    (reverse_low_bits(path, depth) | (1 << depth), depth)
+}
+
+/// Move a node from the magnitude bits of the `IFrac` layout to the `UFrac` bit layout with room for `max_depth` steps. The node must be no more than `max_depth` steps deep.
+pub const fn ifrac_to_ufrac_bits(magnitude: u128, max_depth: u32) -> u128 {
+    if magnitude == 0 {
+        return 0;
+    }
+    let depth = magnitude.ilog2();
+    let path = reverse_low_bits(magnitude, depth);
+    ((path << 1) | 1) << (max_depth - depth)
+}
+
+/// Whether a node in the `UFrac` bit layout with room for `max_depth` steps is greater than the integer `max`, so its path starts with `max` steps right.
+pub const fn ufrac_exceeds(bits: u128, max_depth: u32, max: u32) -> bool {
+    bits != 0
+        && max_depth - bits.trailing_zeros() >= max
+        && (bits << (u128::BITS - 1 - max_depth)).leading_ones() >= max
+}
+
+/// Whether the magnitude bits of the `IFrac` layout hold a node greater than the integer `max`, so its path starts with `max` steps right.
+pub const fn ifrac_exceeds(magnitude: u128, max: u32) -> bool {
+    magnitude != 0 && magnitude.ilog2() >= max && magnitude.trailing_ones() >= max
+}
+
+/// The signed neighbour of a magnitude in the `UFrac` bit layout with room for `max_depth` steps: the next larger one if `up`, the next smaller otherwise. The patterns sort in value order, so with the sign they count in step from `-MAX` to `MAX`.
+///
+/// Returns whether the neighbour is negative, and its magnitude, or `None` past either end.
+pub const fn signed_neighbour(
+    is_negative: bool,
+    bits: u128,
+    max_depth: u32,
+    up: bool,
+) -> Option<(bool, u128)> {
+    let max = (1 << (max_depth + 1)) - 1;
+    #[allow(clippy::cast_possible_wrap)]
+    let index = if is_negative {
+        -(bits as i128)
+    } else {
+        bits as i128
+    };
+    let next = if up { index + 1 } else { index - 1 };
+    if next.unsigned_abs() > max {
+        None
+    } else {
+        Some((next < 0, next.unsigned_abs()))
+    }
+}
+
+/// The deepest node that two nodes in the `UFrac` bit layout with room for `max_depth` steps both lie under, counting each as under itself, along with how many parent and child steps apart the two are. Neither may be `0`.
+pub const fn common_ancestor(a: u128, b: u128, max_depth: u32) -> (u128, u32) {
+    let depth_a = max_depth - a.trailing_zeros();
+    let depth_b = max_depth - b.trailing_zeros();
+    // the paths agree up to the first bit the patterns differ in, or up to where the shallower one ends
+    let mut shared = (a ^ b).leading_zeros() - (u128::BITS - 1 - max_depth);
+    if shared > depth_a {
+        shared = depth_a;
+    }
+    if shared > depth_b {
+        shared = depth_b;
+    }
+    let path = match u128::MAX.checked_shl(max_depth + 1 - shared) {
+        Some(mask) => a & mask,
+        None => 0,
+    };
+    (
+        path | (1 << (max_depth - shared)),
+        depth_a + depth_b - 2 * shared,
+    )
+}
+
+/// The terms of the continued fraction of a node in the `UFrac` bit layout with room for `max_depth` steps, ending in a term of at least `2` unless the node is `0` or `1`.
+///
+/// The path is the continued fraction run-length encoded: `[a0; a1, a2, ..., an]` is `a0` steps right, then `a1` left, `a2` right and so on, with the last run one step short.
+pub fn continued_fraction(bits: u128, max_depth: u32) -> impl Iterator<Item = u32> {
+    let is_zero = bits == 0;
+    let mut remaining = if is_zero {
+        0
+    } else {
+        max_depth - bits.trailing_zeros()
+    };
+    // the path, first step at the top bit
+    let mut path = bits << (127 - max_depth);
+    let mut right = true;
+    let mut is_done = false;
+    std::iter::from_fn(move || {
+        if is_done {
+            return None;
+        } else if is_zero {
+            is_done = true;
+            return Some(0);
+        }
+        let run = if right {
+            path.leading_ones()
+        } else {
+            path.leading_zeros()
+        }
+        .min(remaining);
+        path = path.checked_shl(run).unwrap_or(0);
+        remaining -= run;
+        right = !right;
+        if remaining == 0 {
+            is_done = true;
+            Some(run + 1)
+        } else {
+            Some(run)
+        }
+    })
+}
+
+/// The nearest node within `max_depth` steps to the continued fraction `[a0; a1, ..., an]`, in the `UFrac` bit layout. Values past the deepest node on the right edge of the tree round to that node.
+///
+/// Fails with `FractionError::Infinite` if the terms are empty, which is the convention for infinity, or if a zero term makes them infinite, as in `[3; 0]`.
+pub fn continued_fraction_bits(terms: &[u32], max_depth: u32) -> Result<u128, FractionError> {
+    let terms = normalize_terms(terms)?;
+    if terms == [0] {
+        return Ok(0);
+    }
+    let mut descent = Descent::new(max_depth);
+    for (index, &term) in terms.iter().enumerate() {
+        let right = index % 2 == 0;
+        let run = if index == terms.len() - 1 {
+            term - 1
+        } else {
+            term
+        };
+        let room = descent.room();
+        if run <= room.into() {
+            #[allow(clippy::cast_possible_truncation)]
+            descent.step(right, run as u32);
+        } else {
+            // the rest of the value, relative to the deepest node on its path, is the continued fraction from here on
+            descent.step(right, room);
+            let mut tail = vec![term - u128::from(room)];
+            tail.extend_from_slice(&terms[index + 1..]);
+            let (num, den) = tail_ratio(&tail);
+            return Ok(if right {
+                descent.nearest(num, den)
+            } else {
+                descent.nearest(den, num)
+            });
+        }
+    }
+    Ok(descent.bits())
+}
+
+/// Rewrite a continued fraction so that every term after the first is positive, since `[.., a, 0, b, ..]` is `[.., a + b, ..]` and `[.., a, b, 0]` is `[.., a]`. `[0]` is the only way left to write zero.
+fn normalize_terms(terms: &[u32]) -> Result<Vec<u128>, FractionError> {
+    let mut normalized: Vec<u128> = Vec::with_capacity(terms.len());
+    let mut merge_next = false;
+    for (index, &term) in terms.iter().enumerate() {
+        if merge_next {
+            if let Some(last) = normalized.last_mut() {
+                *last += u128::from(term);
+            }
+            merge_next = false;
+        } else if index > 0 && term == 0 {
+            merge_next = true;
+        } else {
+            normalized.push(term.into());
+        }
+    }
+    if merge_next {
+        normalized.pop();
+    }
+    if normalized.is_empty() {
+        Err(FractionError::Infinite)
+    } else {
+        Ok(normalized)
+    }
+}
+
+/// A ratio that compares with `1` and with every ratio of numerator and denominator below `2^91` the same way as the continued fraction `tail` does, which are all the comparisons [`Descent::nearest`] makes at the bottom of the tree. Every term after the first must be positive.
+///
+/// The terms past the point where that is settled are dropped, so the result fits in a `U256` however long `tail` is.
+fn tail_ratio(tail: &[u128]) -> (U256, U256) {
+    const LIMIT: u128 = 1 << 92;
+    if tail[0] >= LIMIT {
+        return (U256::from_u128(LIMIT), U256::ONE);
+    }
+    let (mut num, mut den) = (U256::from_u128(tail[0]), U256::ONE);
+    let (mut prev_num, mut prev_den) = (U256::ONE, U256::ZERO);
+    for &term in &tail[1..] {
+        let Some(den_wide) = den.to_u128().filter(|&den| den < LIMIT) else {
+            // consecutive convergents are neighbours in the tree, so any ratio strictly between them has a denominator of at least the sum of theirs, and so does their mediant, which is strictly between them like the value
+            return (num.wrapping_add(prev_num), den.wrapping_add(prev_den));
+        };
+        // any term this large or larger leaves the value between the previous convergent and this one, with only larger denominators between them
+        let cap = LIMIT / den_wide + 1;
+        let term = U256::from_u128(term.min(cap));
+        let Some(next_num) = num.mul_add(&term, prev_num) else {
+            unreachable!("convergents stay below `2^190`")
+        };
+        let Some(next_den) = den.mul_add(&term, prev_den) else {
+            unreachable!("convergents stay below `2^190`")
+        };
+        (prev_num, prev_den, num, den) = (num, den, next_num, next_den);
+        if term.eq(U256::from_u128(cap)) {
+            break;
+        }
+    }
+    (num, den)
+}
+
+/// The path to a node in the `UFrac` bit layout with room for `max_depth` steps, as `L` and `R` steps down from the root. The path to `1` is empty, and zero, which isn't on the tree, is `0`.
+pub fn path_string(bits: u128, max_depth: u32) -> String {
+    if bits == 0 {
+        return "0".to_owned();
+    }
+    let depth = max_depth - bits.trailing_zeros();
+    (0..depth)
+        .map(|step| {
+            if (bits >> (max_depth - step)) & 1 == 1 {
+                'R'
+            } else {
+                'L'
+            }
+        })
+        .collect()
+}
+
+/// Read a path written by [`path_string`], optionally preceded by a `-`, for a fraction type `width` bits wide with room for `max_depth` steps.
+///
+/// Returns whether the path is negative, and the node in the `UFrac` bit layout.
+pub fn parse_path(path: &str, width: u32, max_depth: u32) -> Result<(bool, u128), FractionError> {
+    let (is_negative, path) = path
+        .strip_prefix('-')
+        .map_or((false, path), |path| (true, path));
+    if path == "0" {
+        return Ok((is_negative, 0));
+    }
+    if !path.bytes().all(|step| step == b'L' || step == b'R') {
+        return Err(FractionError::InvalidSyntax);
+    }
+    let depth = path.len();
+    // past the largest value, the path starts with more right steps than there's room for
+    if depth > max_depth as usize && !path[..=max_depth as usize].contains('L') {
+        return Err(FractionError::OutOfRange { max: max_depth + 1 });
+    }
+    if depth > max_depth as usize {
+        return Err(FractionError::PrecisionLoss {
+            bits_needed: depth as u128 + u128::from(width - max_depth),
+        });
+    }
+    let steps = path.bytes().fold(0, |steps: u128, step| {
+        (steps << 1) | u128::from(step == b'R')
+    });
+    #[allow(clippy::cast_possible_truncation)]
+    Ok((
+        is_negative,
+        ((steps << 1) | 1) << (max_depth - depth as u32),
+    ))
+}
+
+/// Reverse the order of the low `len` bits of `bits`, dropping the rest.
+const fn reverse_low_bits(bits: u128, len: u32) -> u128 {
+    match bits.reverse_bits().checked_shr(128 - len) {
+        Some(reversed) => reversed,
+        None => 0,
+    }
+}
@@ -0,0 +1,240 @@
+use std::cmp::Ordering;
+
+use crate::{
+    farey::{cmp_ratio, Descent},
+    wide::U256,
+    RoundingMode,
+};
+
+/// Round `num/den` to `digits` significant bits, with ties to even. `num` and `den` must not be zero.
+///
+/// Returns the significand, which has `digits` bits or is `2^digits` after rounding up, and the power of two to scale it by.
+fn round_ratio(num: u128, den: u128, digits: u32) -> (u128, i32) {
+    let (num, den) = (U256::from_u128(num), U256::from_u128(den));
+    // line the operands up so the quotient has two or three bits more than the significand: a rounding bit, and at least one more to go with the remainder as a sticky bit
+    #[allow(clippy::cast_possible_wrap)]
+    let shift = num.bit_len() as i32 - den.bit_len() as i32 - (digits + 2) as i32;
+    let (quotient, remainder) = if shift >= 0 {
+        num.div_rem(den.shl(shift.unsigned_abs()))
+    } else {
+        num.shl(shift.unsigned_abs()).div_rem(den)
+    };
+    let Some(quotient) = quotient.to_u128() else {
+        unreachable!("the quotient has at most `digits + 3` bits")
+    };
+    let extra = 128 - quotient.leading_zeros() - digits;
+    let significand = quotient >> extra;
+    let rest = quotient & ((1 << extra) - 1);
+    let half = 1 << (extra - 1);
+    let round_up = rest > half || (rest == half && (!remainder.is_zero() || significand & 1 == 1));
+    #[allow(clippy::cast_possible_wrap)]
+    (significand + u128::from(round_up), shift + extra as i32)
+}
+
+/// `num/den` as the nearest `f64`, rounding only once. `den` must not be zero.
+pub fn ratio_to_f64(num: u128, den: u128) -> f64 {
+    if num == 0 {
+        return 0.0;
+    }
+    let (significand, exponent) = round_ratio(num, den, f64::MANTISSA_DIGITS);
+    // both factors are exact, so the product is too
+    #[allow(clippy::cast_precision_loss)]
+    let significand = significand as f64;
+    significand * 2f64.powi(exponent)
+}
+
+/// `num/den` as the nearest `f32`, rounding only once. `den` must not be zero.
+#[allow(clippy::cast_possible_truncation)]
+pub fn ratio_to_f32(num: u128, den: u128) -> f32 {
+    if num == 0 {
+        return 0.0;
+    }
+    let (significand, exponent) = round_ratio(num, den, f32::MANTISSA_DIGITS);
+    // the value already has few enough bits for an `f32`, so narrowing it from `f64` is exact
+    #[allow(clippy::cast_precision_loss)]
+    let significand = significand as f64;
+    (significand * 2f64.powi(exponent)) as f32
+}
+
+/// A float type to walk down the Farey tree in, so that every comparison along the way rounds the way that type does.
+pub trait Float: Copy + PartialOrd {
+    /// The nearest value to an integer
+    fn from_u128(value: u128) -> Self;
+    /// The product of two values, rounded to this type's precision
+    fn mul(self, rhs: Self) -> Self;
+    fn abs(self) -> Self;
+    /// The exact same value as an `f64`
+    fn to_f64(self) -> f64;
+}
+
+#[allow(clippy::cast_precision_loss)]
+impl Float for f64 {
+    fn from_u128(value: u128) -> Self {
+        value as Self
+    }
+    fn mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn abs(self) -> Self {
+        self.abs()
+    }
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+impl Float for f32 {
+    fn from_u128(value: u128) -> Self {
+        value as Self
+    }
+    fn mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn abs(self) -> Self {
+        self.abs()
+    }
+    fn to_f64(self) -> f64 {
+        self.into()
+    }
+}
+
+/// An IEEE half-precision float, held in an `f32` that is rounded to half precision after every operation.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub struct F16(f32);
+
+impl F16 {
+    /// The largest finite half-precision value
+    const MAX: f32 = 65504.0;
+
+    /// Decode a half-precision bit pattern.
+    pub fn from_bits(bits: u16) -> Self {
+        let sign = if bits >> 15 == 1 { -1.0 } else { 1.0 };
+        let exponent = i32::from((bits >> 10) & 0x1f);
+        let significand = f32::from(bits & 0x3ff);
+        Self(
+            sign * match exponent {
+                0x1f if significand == 0.0 => f32::INFINITY,
+                0x1f => f32::NAN,
+                // subnormal
+                0 => significand * 2f32.powi(-24),
+                _ => (significand + 1024.0) * 2f32.powi(exponent - 25),
+            },
+        )
+    }
+
+    /// Round an `f32` to half precision, with ties to even.
+    fn round(value: f32) -> Self {
+        let magnitude = value.abs();
+        let rounded = if magnitude < 2f32.powi(-14) {
+            // subnormals are all multiples of `2^-24`
+            (magnitude * 2f32.powi(24)).round_ties_even() * 2f32.powi(-24)
+        } else {
+            // keep the top 11 of the 24 significant bits; a carry out of the significand moves into the exponent, as it should
+            let bits = magnitude.to_bits();
+            f32::from_bits((bits + 0xfff + ((bits >> 13) & 1)) & !0x1fff)
+        };
+        Self(
+            if rounded > Self::MAX {
+                f32::INFINITY
+            } else {
+                rounded
+            }
+            .copysign(value),
+        )
+    }
+}
+
+impl Float for F16 {
+    #[allow(clippy::cast_precision_loss)]
+    fn from_u128(value: u128) -> Self {
+        if value > 1 << 16 {
+            Self(f32::INFINITY)
+        } else {
+            // exact in an `f32`, so this rounds only once
+            Self::round(value as f32)
+        }
+    }
+    fn mul(self, rhs: Self) -> Self {
+        // two 11-bit significands multiply exactly in an `f32`'s 24 bits
+        Self::round(self.0 * rhs.0)
+    }
+    fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+    fn to_f64(self) -> f64 {
+        self.0.into()
+    }
+}
+
+/// Walk down the Farey tree towards a positive, finite `value`, comparing each node with it in `F`'s own precision, and stop on reaching a node equal to `value` at that precision. If there is none within `max_depth` steps, round the exact value of `value` to the nearest node instead.
+///
+/// Returns the node in the `UFrac` bit layout with room for `max_depth` steps.
+pub fn descend<F: Float>(value: F, max_depth: u32) -> u128 {
+    let mut descent = Descent::new(max_depth);
+    loop {
+        let (num, denom) = descent.node();
+        match F::from_u128(num).partial_cmp(&value.mul(F::from_u128(denom))) {
+            Some(Ordering::Equal) | None => return descent.bits(),
+            Some(_) if descent.is_at_max_depth() => {
+                let (num, den) = exact_ratio(value.to_f64());
+                return Descent::new(max_depth).nearest(num, den);
+            }
+            Some(ordering) => descent.step(ordering == Ordering::Less, 1),
+        }
+    }
+}
+
+/// The exact value of a positive, finite `value` as a ratio, clamped to between `2^-140` and `2^8`.
+///
+/// No `UFrac` or `IFrac` is smaller than `1/128` or larger than `128`, so values past either end round the same way once clamped.
+fn exact_ratio(value: f64) -> (U256, U256) {
+    let value = value.clamp(2f64.powi(-140), 2f64.powi(8));
+    // a normal float is its 53-bit significand times a power of two, which here is always negative
+    let bits = value.to_bits();
+    let significand = (bits & ((1 << 52) - 1)) | (1 << 52);
+    #[allow(clippy::cast_possible_truncation)]
+    let shift = 1075 - (bits >> 52) as u32;
+    (U256::from_u128(significand.into()), U256::ONE.shl(shift))
+}
+
+/// Round a positive, finite `value` to a node no more than `max_depth` steps deep, in the `UFrac` bit layout. Returns `None` if rounding up past the deepest node on the right edge of the tree.
+pub fn round(value: f64, max_depth: u32, mode: RoundingMode) -> Option<u128> {
+    let (num, den) = exact_ratio(value);
+    let descent = Descent::new(max_depth);
+    match mode {
+        RoundingMode::Nearest => Some(descent.nearest(num, den)),
+        RoundingMode::Floor | RoundingMode::TowardZero => Some(descent.floor(num, den)),
+        RoundingMode::Ceil => descent.ceil(num, den),
+        RoundingMode::Simplest { tolerance } => {
+            Some(simplest(value, tolerance, max_depth).unwrap_or_else(|| descent.nearest(num, den)))
+        }
+    }
+}
+
+/// The node closest to the root within `tolerance` of a positive, finite `value`, in the `UFrac` bit layout.
+///
+/// The nodes within any interval all descend from the shallowest one, so it is the first one met on the way down to `value`.
+fn simplest(value: f64, tolerance: f64, max_depth: u32) -> Option<u128> {
+    if value <= tolerance {
+        return Some(0);
+    }
+    let (num, den) = exact_ratio(value);
+    let mut descent = Descent::new(max_depth);
+    loop {
+        let (node_num, node_den) = descent.node();
+        if (ratio_to_f64(node_num, node_den) - value).abs() <= tolerance {
+            return Some(descent.bits());
+        } else if descent.is_at_max_depth() {
+            return None;
+        }
+        let right = cmp_ratio(
+            num,
+            den,
+            U256::from_u128(node_num),
+            U256::from_u128(node_den),
+        )
+        .is_gt();
+        descent.step(right, 1);
+    }
+}
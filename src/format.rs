@@ -0,0 +1,142 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+/// An unsigned integer type to write ratios in, which is `u128` for the fixed-width fractions and `BigUint` for `BigUFrac`.
+pub trait Digits:
+    Clone
+    + Display
+    + Ord
+    + From<u8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+}
+
+impl<N> Digits for N where
+    N: Clone
+        + Display
+        + Ord
+        + From<u8>
+        + Add<Output = Self>
+        + Sub<Output = Self>
+        + Mul<Output = Self>
+        + Div<Output = Self>
+        + Rem<Output = Self>
+{
+}
+
+/// Write `num/den` with the formatter's flags: a precision writes a decimal rounded to that many places with ties to even, `#` writes a mixed number like `2 5/7`, and `+`, the width, fill and `0` flag work as they do for integers. `den` must not be zero, and if `N` is `u128` it must be less than `2^124`.
+pub fn write_ratio<N: Digits>(
+    f: &mut Formatter<'_>,
+    is_negative: bool,
+    num: &N,
+    den: &N,
+) -> Result {
+    let (whole, remainder) = (num.clone() / den.clone(), num.clone() % den.clone());
+    let zero = N::from(0);
+    let body = match f.precision() {
+        Some(places) => rounded_decimal(whole, remainder, den, places),
+        None if f.alternate() => {
+            if remainder == zero {
+                whole.to_string()
+            } else if whole == zero {
+                format!("{remainder}/{den}")
+            } else {
+                format!("{whole} {remainder}/{den}")
+            }
+        }
+        None => format!("{num}/{den}"),
+    };
+    f.pad_integral(!is_negative, "", &body)
+}
+
+/// `whole + remainder/den` as a decimal with `places` digits after the point, rounded with ties to even.
+fn rounded_decimal<N: Digits>(mut whole: N, mut remainder: N, den: &N, places: usize) -> String {
+    let mut digits = Vec::with_capacity(places);
+    for _ in 0..places {
+        remainder = remainder * N::from(10);
+        // the digit is at most 9, so find it by subtracting
+        let mut digit = 0;
+        while remainder >= *den {
+            remainder = remainder - den.clone();
+            digit += 1;
+        }
+        digits.push(digit);
+    }
+    let is_odd = digits.last().map_or_else(
+        || whole.clone() % N::from(2) == N::from(1),
+        |digit| digit & 1 == 1,
+    );
+    let twice = remainder * N::from(2);
+    if twice > *den || (twice == *den && is_odd) {
+        // carry the rounding up through any trailing nines
+        if let Some(index) = digits.iter().rposition(|&digit| digit != 9) {
+            digits[index] += 1;
+            digits[index + 1..].fill(0);
+        } else {
+            digits.fill(0);
+            whole = whole + N::from(1);
+        }
+    }
+    let mut decimal = whole.to_string();
+    if places > 0 {
+        decimal.push('.');
+        decimal.extend(digits.iter().map(|&digit| char::from(b'0' + digit)));
+    }
+    decimal
+}
+
+/// `num/den` as an exact decimal with at most `digits` digits after the point, with any repeating digits in parentheses, like `0.1(6)`. An expansion that neither ends nor starts repeating within `digits` digits is cut off there and ends in `...`. `den` must not be zero, and must be less than `2^124`.
+pub fn decimal_string(is_negative: bool, num: u128, den: u128, digits: usize) -> String {
+    let mut decimal = if is_negative && num != 0 {
+        format!("-{}", num / den)
+    } else {
+        (num / den).to_string()
+    };
+    let mut remainder = num % den;
+    if remainder == 0 {
+        return decimal;
+    }
+    decimal.push('.');
+    let fraction_start = decimal.len();
+    // the remainder before each digit; once one comes around again, so do the digits after it
+    let mut remainders = Vec::new();
+    loop {
+        if remainder == 0 {
+            break;
+        } else if let Some(start) = remainders.iter().position(|&seen| seen == remainder) {
+            decimal.insert(fraction_start + start, '(');
+            decimal.push(')');
+            break;
+        } else if remainders.len() == digits {
+            if digits == 0 {
+                decimal.pop();
+            }
+            decimal.push_str("...");
+            break;
+        }
+        remainders.push(remainder);
+        remainder *= 10;
+        #[allow(clippy::cast_possible_truncation)]
+        decimal.push(char::from(b'0' + (remainder / den) as u8));
+        remainder %= den;
+    }
+    decimal
+}
+
+/// Write a fraction for `Debug`: the type's name, then its value and its bit pattern grouped in fours from the right, like `UFrac8(3/7 @ 0b0011_1000)`.
+pub fn write_debug(f: &mut Formatter<'_>, name: &str, value: &dyn Display, bits: &str) -> Result {
+    let mut grouped = String::with_capacity(bits.len() * 5 / 4);
+    for (index, bit) in bits.chars().enumerate() {
+        if index > 0 && (bits.len() - index).is_multiple_of(4) {
+            grouped.push('_');
+        }
+        grouped.push(bit);
+    }
+    write!(f, "{name}({value} @ 0b{grouped})")
+}
@@ -0,0 +1,54 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+/// The operations shared by every fixed-width unsigned fraction, `UFrac<BITS>`, so that code walking the Farey tree can be written once and used with any of them.
+///
+/// Each method behaves exactly like the inherent method of the same name.
+pub trait FareyFraction: Copy + Eq + Ord + Hash + Default + Debug + Display {
+    /// The unsigned integer holding the bit pattern
+    type Bits: Copy;
+    /// The unsigned integer type of the numerator and denominator
+    type Int: Copy;
+
+    /// How many steps down the Farey tree a value can be; the highest value of `precision`
+    const MAX_PRECISION: u32;
+    /// Zero, the value with no path
+    const ZERO: Self;
+    /// Lowest non-zero value
+    const MIN: Self;
+    /// One, the root of the Farey tree
+    const ONE: Self;
+    /// Highest value
+    const MAX: Self;
+
+    /// Convert into a numerator and denominator.
+    fn to_fraction(self) -> (Self::Int, Self::Int);
+    /// Construct a value from a bit pattern
+    fn from_bits(bits: Self::Bits) -> Self;
+    /// Get the bit pattern out of a value
+    fn to_bits(self) -> Self::Bits;
+    /// How many steps down the Farey tree the value is
+    fn precision(self) -> u32;
+    /// The value's parent node on the Farey tree, or `None` for `0` and `1`
+    fn parent(self) -> Option<Self>;
+    /// The value's left child node on the Farey tree, or `None` for `0` and leaves
+    fn left_child(self) -> Option<Self>;
+    /// The value's right child node on the Farey tree, or `None` for `0` and leaves
+    fn right_child(self) -> Option<Self>;
+    /// Both of the value's child nodes on the Farey tree, or `None` for `0` and leaves
+    fn children(self) -> Option<(Self, Self)>;
+    /// The other child of the value's parent node, or `None` for `0` and `1`
+    fn sibling(self) -> Option<Self>;
+    /// Whether the value is as deep as the type allows, so it has no children
+    fn is_leaf(self) -> bool;
+    /// The inverse of the value
+    ///
+    /// # Panics
+    /// If `self` is equal to `0`
+    #[must_use]
+    fn invert(self) -> Self;
+    /// The inverse of the value, or `None` for `0`
+    fn try_invert(self) -> Option<Self>;
+}
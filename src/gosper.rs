@@ -0,0 +1,198 @@
+use crate::{farey::Descent, wide::U256};
+
+/// One input to a [`Bihomographic`] function: the path down the Farey tree to a node, first step in the most significant of `len` bits.
+#[derive(Clone, Copy, Debug)]
+pub struct Operand {
+    path: u128,
+    len: u32,
+    /// Whether this is `0`, which has no path, rather than a node
+    is_zero: bool,
+}
+
+impl Operand {
+    pub const ZERO: Self = Self {
+        path: 0,
+        len: 0,
+        is_zero: true,
+    };
+
+    /// Read the path out of a bit pattern in the `UFrac` layout with room for `max_depth` steps.
+    pub const fn from_ufrac_bits(bits: u128, max_depth: u32) -> Self {
+        if bits == 0 {
+            return Self::ZERO;
+        }
+        let trailing_zeros = bits.trailing_zeros();
+        Self {
+            path: match bits.checked_shr(trailing_zeros + 1) {
+                Some(path) => path,
+                None => 0,
+            },
+            len: max_depth - trailing_zeros,
+            is_zero: false,
+        }
+    }
+
+    /// Take the next step of the path, `true` for a step to the right.
+    const fn next_step(&mut self) -> Option<bool> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            Some((self.path >> self.len) & 1 == 1)
+        }
+    }
+}
+
+/// The function `(a·x·y + b·x + c·y + d) / (e·x·y + f·x + g·y + h)`, evaluated one path step at a time in the style of Gosper's continued fraction arithmetic.
+///
+/// Each step of `x` or `y` is folded into the coefficients, and each step of the result is emitted as soon as every value the inputs could still take agrees on it. That keeps the coefficients close to the size of the inputs' numerators and denominators, so no intermediate product ever has to be formed.
+#[derive(Clone, Copy, Debug)]
+pub struct Bihomographic {
+    /// The numerator's coefficients of `x·y`, `x`, `y` and `1`, in two's complement
+    num: [U256; 4],
+    /// The denominator's coefficients of `x·y`, `x`, `y` and `1`, in two's complement
+    denom: [U256; 4],
+}
+
+impl Bihomographic {
+    /// `x + y`
+    pub const SUM: Self = Self {
+        num: [U256::ZERO, U256::ONE, U256::ONE, U256::ZERO],
+        denom: Self::ONE,
+    };
+    /// `x - y`, which must only be evaluated where `x >= y`
+    pub const DIFFERENCE: Self = Self {
+        num: [U256::ZERO, U256::ONE, U256::from_i128(-1), U256::ZERO],
+        denom: Self::ONE,
+    };
+    /// `x * y`
+    pub const PRODUCT: Self = Self {
+        num: [U256::ONE, U256::ZERO, U256::ZERO, U256::ZERO],
+        denom: Self::ONE,
+    };
+
+    /// The coefficients of the constant `1`
+    const ONE: [U256; 4] = [U256::ZERO, U256::ZERO, U256::ZERO, U256::ONE];
+
+    /// Evaluate the function at `x` and `y`, emitting at most `max_depth` steps of the result.
+    ///
+    /// Returns the walk down to the emitted node along with the rest of the exact result, relative to that node, so the caller can decide how to round it.
+    pub const fn evaluate(
+        mut self,
+        mut x: Operand,
+        mut y: Operand,
+        max_depth: u32,
+    ) -> (Descent, U256, U256) {
+        let mut output = Descent::new(max_depth);
+        loop {
+            while !output.is_at_max_depth() {
+                let difference = Self::difference(self.num, self.denom);
+                if Self::is_positive(difference) {
+                    // the result is greater than one everywhere, so step right: `z - 1`
+                    self.num = difference;
+                    output.step(true, 1);
+                } else if Self::is_positive(Self::difference(self.denom, self.num)) {
+                    // the result is less than one everywhere, so step left: `z / (1 - z)`
+                    self.denom = Self::difference(self.denom, self.num);
+                    output.step(false, 1);
+                } else {
+                    break;
+                }
+            }
+            let took_x = self.take_x(&mut x);
+            let took_y = self.take_y(&mut y);
+            if !took_x && !took_y {
+                break;
+            }
+        }
+        // both inputs are used up, so only the constant terms are left
+        (output, self.num[3], self.denom[3])
+    }
+
+    /// Fold the next step of `x` into the coefficients. Once `x` is used up, substitute its final value and return `false`.
+    const fn take_x(&mut self, x: &mut Operand) -> bool {
+        let step = x.next_step();
+        Self::fold_x(&mut self.num, step, x.is_zero);
+        Self::fold_x(&mut self.denom, step, x.is_zero);
+        step.is_some()
+    }
+
+    /// Fold the next step of `y` into the coefficients. Once `y` is used up, substitute its final value and return `false`.
+    const fn take_y(&mut self, y: &mut Operand) -> bool {
+        let step = y.next_step();
+        Self::fold_y(&mut self.num, step, y.is_zero);
+        Self::fold_y(&mut self.denom, step, y.is_zero);
+        step.is_some()
+    }
+
+    const fn fold_x(coefficients: &mut [U256; 4], step: Option<bool>, is_zero: bool) {
+        let [xy, x, y, constant] = coefficients;
+        match step {
+            // x -> x + 1
+            Some(true) => {
+                *y = y.wrapping_add(*xy);
+                *constant = constant.wrapping_add(*x);
+            }
+            // x -> x / (x + 1), then clear the new denominator
+            Some(false) => {
+                *xy = xy.wrapping_add(*y);
+                *x = x.wrapping_add(*constant);
+            }
+            // x -> 1, or x -> 0
+            None => {
+                if !is_zero {
+                    *y = y.wrapping_add(*xy);
+                    *constant = constant.wrapping_add(*x);
+                }
+                *xy = U256::ZERO;
+                *x = U256::ZERO;
+            }
+        }
+    }
+
+    const fn fold_y(coefficients: &mut [U256; 4], step: Option<bool>, is_zero: bool) {
+        let [xy, x, y, constant] = coefficients;
+        match step {
+            // y -> y + 1
+            Some(true) => {
+                *x = x.wrapping_add(*xy);
+                *constant = constant.wrapping_add(*y);
+            }
+            // y -> y / (y + 1), then clear the new denominator
+            Some(false) => {
+                *xy = xy.wrapping_add(*x);
+                *y = y.wrapping_add(*constant);
+            }
+            // y -> 1, or y -> 0
+            None => {
+                if !is_zero {
+                    *x = x.wrapping_add(*xy);
+                    *constant = constant.wrapping_add(*y);
+                }
+                *xy = U256::ZERO;
+                *y = U256::ZERO;
+            }
+        }
+    }
+
+    const fn difference(lhs: [U256; 4], rhs: [U256; 4]) -> [U256; 4] {
+        [
+            lhs[0].wrapping_sub(rhs[0]),
+            lhs[1].wrapping_sub(rhs[1]),
+            lhs[2].wrapping_sub(rhs[2]),
+            lhs[3].wrapping_sub(rhs[3]),
+        ]
+    }
+
+    /// Whether `a·x·y + b·x + c·y + d` is positive for every positive `x` and `y`.
+    ///
+    /// Its sign over the whole quadrant is bounded by its limits at the four corners, which are just the four coefficients.
+    const fn is_positive(coefficients: [U256; 4]) -> bool {
+        let [xy, x, y, constant] = coefficients;
+        !xy.is_negative()
+            && !x.is_negative()
+            && !y.is_negative()
+            && !constant.is_negative()
+            && !(xy.is_zero() && x.is_zero() && y.is_zero() && constant.is_zero())
+    }
+}
@@ -1,8 +1,24 @@
 #![warn(clippy::nursery, clippy::pedantic)]
-mod ifrac8;
+mod direction;
+mod error;
+mod farey;
+mod float;
+mod format;
+mod fraction;
+mod gosper;
+mod parse;
+mod rounding;
+mod signed;
 #[cfg(test)]
 mod tests;
 mod unsigned;
+mod wide;
 
-pub use ifrac8::IFrac8;
-pub use unsigned::{UFrac16, UFrac32, UFrac64, UFrac8};
+pub use direction::Direction;
+pub use error::FractionError;
+pub use fraction::FareyFraction;
+pub use rounding::RoundingMode;
+pub use signed::{IFrac16, IFrac32, IFrac64, IFrac8};
+#[cfg(feature = "big")]
+pub use unsigned::BigUFrac;
+pub use unsigned::{BitWidth, UFrac, UFrac128, UFrac16, UFrac32, UFrac64, UFrac8, Width};
@@ -0,0 +1,156 @@
+#[cfg(feature = "big")]
+use num_bigint::BigUint;
+
+use crate::{
+    farey::{depth, Descent},
+    wide::U256,
+    FractionError,
+};
+
+/// An unsigned integer type to read the numbers in a fraction literal into.
+pub trait Natural: Sized {
+    fn from_u32(value: u32) -> Self;
+    /// `self * factor + addend`, or `None` if it doesn't fit
+    fn mul_add(self, factor: &Self, addend: Self) -> Option<Self>;
+    /// Whether `self` is greater than `value`
+    fn exceeds(&self, value: u32) -> bool;
+}
+
+impl Natural for U256 {
+    fn from_u32(value: u32) -> Self {
+        Self::from_u128(value.into())
+    }
+    fn mul_add(self, factor: &Self, addend: Self) -> Option<Self> {
+        self.checked_mul(*factor)?.checked_add(addend)
+    }
+    fn exceeds(&self, value: u32) -> bool {
+        self.cmp(Self::from_u32(value)).is_gt()
+    }
+}
+
+#[cfg(feature = "big")]
+impl Natural for BigUint {
+    fn from_u32(value: u32) -> Self {
+        value.into()
+    }
+    fn mul_add(self, factor: &Self, addend: Self) -> Option<Self> {
+        Some(self * factor + addend)
+    }
+    fn exceeds(&self, value: u32) -> bool {
+        *self > Self::from(value)
+    }
+}
+
+/// Denominators and decimal fractions too long for `N` to hold, which only happens with `U256`. How deep they are in the tree is out of reach, so they count as needing as many bits as there are.
+const TOO_LONG: FractionError = FractionError::PrecisionLoss {
+    bits_needed: u128::MAX,
+};
+
+/// Read a fraction literal: an optional sign, then an integer like `"3"`, a ratio like `"3/7"`, a mixed number like `"1 1/2"` or a decimal like `"0.375"`.
+///
+/// Returns whether the literal is negative, and its exact value as a numerator and denominator, which aren't necessarily in lowest terms. The denominator is `0` if the literal divides by zero.
+///
+/// A whole part or numerator too long for `N` to hold is far past `max`, the largest magnitude of the type being parsed, so it fails with `FractionError::OutOfRange`, or `FractionError::Negative` if it's negative and the type is unsigned. Any other number too long to hold fails with `FractionError::PrecisionLoss`, unless the whole part is past `max` anyway.
+pub fn parse<N: Natural>(
+    text: &str,
+    max: u32,
+    is_signed: bool,
+) -> Result<(bool, N, N), FractionError> {
+    let (is_negative, body) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    let too_large = if is_negative && !is_signed {
+        FractionError::Negative
+    } else {
+        FractionError::OutOfRange { max }
+    };
+    // past the whole part, a number too long to hold is too deep in the tree, unless the whole part is out of range already
+    let too_long = |whole: &N| {
+        if whole.exceeds(max) {
+            too_large
+        } else {
+            TOO_LONG
+        }
+    };
+    let (whole, num, den) = if let Some((whole, fraction)) = body.split_once('.') {
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(FractionError::InvalidSyntax);
+        }
+        // trailing zeros don't change the value, but each would multiply the denominator by ten
+        let fraction = fraction.trim_end_matches('0');
+        // either side of the point may be left out, as in `".5"` or `"5."`
+        let digits_or_zero = |digits: &str, overflow| {
+            if digits.is_empty() {
+                Ok(N::from_u32(0))
+            } else {
+                read_digits::<N>(digits, overflow)
+            }
+        };
+        let whole = digits_or_zero(whole, too_large)?;
+        let too_long = too_long(&whole);
+        let num = digits_or_zero(fraction, too_long)?;
+        let den = fraction
+            .bytes()
+            .try_fold(N::from_u32(1), |power, _| {
+                power.mul_add(&N::from_u32(10), N::from_u32(0))
+            })
+            .ok_or(too_long)?;
+        (whole, num, den)
+    } else if let Some((numerator, den)) = body.split_once('/') {
+        let (whole, num) = match numerator.split_once(' ') {
+            Some((whole, num)) => (
+                read_digits(whole, too_large)?,
+                read_digits(num.trim_start_matches(' '), too_large)?,
+            ),
+            None => (N::from_u32(0), read_digits(numerator, too_large)?),
+        };
+        let den = read_digits(den, too_long(&whole))?;
+        (whole, num, den)
+    } else {
+        (
+            read_digits(body, too_large)?,
+            N::from_u32(0),
+            N::from_u32(1),
+        )
+    };
+    let overflow = too_long(&whole);
+    let num = whole.mul_add(&den, num).ok_or(overflow)?;
+    Ok((is_negative, num, den))
+}
+
+/// Read a non-empty string of decimal digits, failing with `overflow` if they're too long for `N` to hold.
+fn read_digits<N: Natural>(digits: &str, overflow: FractionError) -> Result<N, FractionError> {
+    if digits.is_empty() {
+        return Err(FractionError::InvalidSyntax);
+    }
+    digits.bytes().try_fold(N::from_u32(0), |value, digit| {
+        if digit.is_ascii_digit() {
+            value
+                .mul_add(&N::from_u32(10), N::from_u32((digit - b'0').into()))
+                .ok_or(overflow)
+        } else {
+            Err(FractionError::InvalidSyntax)
+        }
+    })
+}
+
+/// The node at exactly `num/den`, in the `UFrac` bit layout with room for `max_depth` steps, for a fraction type `width` bits wide.
+pub fn exact_bits(num: U256, den: U256, width: u32, max_depth: u32) -> Result<u128, FractionError> {
+    if den.is_zero() {
+        return Err(FractionError::Infinite);
+    }
+    // the largest value `max_depth` steps deep is the end of the right edge
+    let max = max_depth + 1;
+    let (whole, remainder) = num.div_rem(den);
+    let max_whole = U256::from_u128(max.into());
+    if whole.cmp(max_whole).is_gt() || (whole.eq(max_whole) && !remainder.is_zero()) {
+        return Err(FractionError::OutOfRange { max });
+    }
+    Descent::new(max_depth)
+        .exact(num, den)
+        .ok_or_else(|| FractionError::PrecisionLoss {
+            bits_needed: depth(num, den).saturating_add((width - max_depth).into()),
+        })
+}
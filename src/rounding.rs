@@ -0,0 +1,28 @@
+/// How to pick a fraction for a float that falls between two nodes of the Farey tree.
+///
+/// Unlike `TryFrom<f64>`, which walks the tree comparing in floating point, every mode but `Simplest` compares with the exact value of the float, so `Floor` and `Ceil` are guaranteed bounds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RoundingMode {
+    /// The nearest node, or the one closer to the root when the value is exactly halfway between two
+    Nearest,
+    /// The greatest node that isn't greater than the value
+    Floor,
+    /// The least node that isn't less than the value
+    Ceil,
+    /// The nearest node towards zero; `Floor` for positive values and `Ceil` for negative ones
+    TowardZero,
+    /// The node closest to the root that is within `tolerance` of the value, or the nearest node if none is
+    Simplest { tolerance: f64 },
+}
+
+impl RoundingMode {
+    /// The mode to round the magnitude of a value with, so that rounding the value keeps its meaning.
+    pub(crate) const fn for_magnitude(self, is_negative: bool) -> Self {
+        match self {
+            Self::Floor if is_negative => Self::Ceil,
+            Self::Ceil if is_negative => Self::Floor,
+            Self::TowardZero => Self::Floor,
+            mode => mode,
+        }
+    }
+}
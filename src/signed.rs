@@ -0,0 +1,21 @@
+use std::fmt::{Binary, LowerHex, Octal, UpperHex};
+
+mod ifrac;
+
+pub use ifrac::{IFrac16, IFrac32, IFrac64, IFrac8};
+
+macro_rules! bit_formats {
+    ($($frac:ident)*) => {$(
+        bit_formats!(@formats $frac: Binary Octal LowerHex UpperHex);
+    )*};
+    (@formats $frac:ident: $($format:ident)*) => {$(
+        impl $format for $frac {
+            /// Writes the bit pattern, the same way as for the unsigned integer that holds it.
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                $format::fmt(&self.to_bits(), f)
+            }
+        }
+    )*};
+}
+
+bit_formats!(IFrac8 IFrac16 IFrac32 IFrac64);
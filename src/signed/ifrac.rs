@@ -0,0 +1,542 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use crate::{
+    farey::{
+        common_ancestor, ifrac_exceeds, ifrac_to_ufrac_bits, parse_path, path_string,
+        signed_neighbour, ufrac_exceeds, ufrac_to_ifrac_bits,
+    },
+    float::{descend, ratio_to_f32, ratio_to_f64, round, Float, F16},
+    format::{decimal_string, write_debug, write_ratio},
+    parse::{exact_bits, parse},
+    wide::U256,
+    BitWidth, FractionError, RoundingMode, UFrac, Width,
+};
+
+/// One signed fraction type per width: the sign in the top bit, then the magnitude's path down the tree from the least significant bit up, ended by a `1`. `$max` is the largest magnitude, one less than the width, and `$max_depth` the deepest a value goes, one less again.
+macro_rules! signed_fracs {
+    ($(
+        $frac:ident($bits:ident, $int:ident, $precision:ident): $width:literal $max:literal $max_depth:literal {
+            layout: $short:literal $long:literal,
+            debug: $debug:literal,
+            MIN: $min_bits:literal = $min_value:literal,
+            GOLDEN_RATIO: $golden_bits:literal = $golden_value:literal,
+            E: $e_bits:literal = $e_value:literal,
+            PI: $pi_bits:literal = $pi_value:literal,
+            MAX: $max_bits:literal,
+        }
+    )*) => {$(
+        /// A signed fraction defined along a binary tree.
+        ///
+        /// 0s, 1, xs
+        ///
+        #[doc = $short]
+        ///
+        #[doc = $long]
+        #[derive(Eq, Default, Clone, Copy)]
+        pub struct $frac($bits);
+
+        impl PartialEq for $frac {
+            /// Compares by value, so `0` with the sign bit set equals `0`.
+            fn eq(&self, other: &Self) -> bool {
+                self.canonical_bits() == other.canonical_bits()
+            }
+        }
+
+        impl Hash for $frac {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.canonical_bits().hash(state);
+            }
+        }
+
+        impl Debug for $frac {
+            #[doc = concat!("Writes the value and the bit pattern, like `", stringify!($frac), "(-3/7 @ ", $debug, ")`. Use `{:b}` for the bits alone.")]
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write_debug(f, stringify!($frac), self, &format!("{:0>width$b}", self.0, width = $width))
+            }
+        }
+
+        impl Display for $frac {
+            /// Writes `num/den`, or a mixed number like `-2 5/7` with `{:#}`, or a decimal rounded to the given number of places with `{:.6}`. Decimals are rounded once from the exact value, with ties to even. `{:+}` writes the sign of positive values too.
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let (num, denom) = self.to_fraction();
+                write_ratio(
+                    f,
+                    num < 0,
+                    &u128::from(num.unsigned_abs()),
+                    &u128::from(denom.unsigned_abs()),
+                )
+            }
+        }
+
+        impl FromStr for $frac {
+            type Err = FractionError;
+            /// Parse an integer like `"3"`, a ratio like `"-2/5"`, a mixed number like `"-1 1/2"` or a decimal like `"0.375"`, which must be exactly representable. Decimals are read exactly, not through a float.
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                let (is_negative, num, den) = parse::<U256>(text, $max, true)?;
+                let bits = exact_bits(num, den, $width, $max_depth)?;
+                Ok(Self::from_ufrac_bits(bits, is_negative && bits != 0))
+            }
+        }
+
+        impl PartialOrd for $frac {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $frac {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.signed_index().cmp(&other.signed_index())
+            }
+        }
+
+        impl $frac {
+            pub const ZERO: Self = Self(0);
+            #[doc = concat!("Smallest positive value represented by `", stringify!($frac), "`; equal to ", $min_value)]
+            pub const MIN: Self = Self($min_bits);
+            pub const ONE: Self = Self(1);
+            #[doc = concat!("The Golden Ratio approximated as an `", stringify!($frac), "`; equal to ", $golden_value)]
+            pub const GOLDEN_RATIO: Self = Self($golden_bits);
+            #[doc = concat!("Euler's Number approximated as an `", stringify!($frac), "`; equal to ", $e_value)]
+            pub const E: Self = Self($e_bits);
+            #[doc = concat!("Pi approximated as an `", stringify!($frac), "`; equal to ", $pi_value)]
+            pub const PI: Self = Self($pi_bits);
+            #[doc = concat!("Highest value represented by `", stringify!($frac), "`; equal to ", $max)]
+            pub const MAX: Self = Self($max_bits);
+
+            #[doc = concat!("Convert an `", stringify!($frac), "` into two `", stringify!($int), "`s representing the numerator and denominator.")]
+            #[must_use]
+            pub fn to_fraction(self) -> ($int, $int) {
+                self.bounds().1
+            }
+
+            /// The nearest `f64` to the value. The division is done exactly and rounded once, so this is correct even where the numerator and denominator don't fit in an `f64` on their own.
+            #[must_use]
+            pub fn to_f64(self) -> f64 {
+                let (num, denom) = self.to_fraction();
+                let magnitude = ratio_to_f64(num.unsigned_abs().into(), denom.unsigned_abs().into());
+                if num < 0 { -magnitude } else { magnitude }
+            }
+
+            /// The nearest `f32` to the value, rounded once like [`Self::to_f64`].
+            #[must_use]
+            pub fn to_f32(self) -> f32 {
+                let (num, denom) = self.to_fraction();
+                let magnitude = ratio_to_f32(num.unsigned_abs().into(), denom.unsigned_abs().into());
+                if num < 0 { -magnitude } else { magnitude }
+            }
+
+            /// The open interval the subtree under `self` covers, as `(lower, self, upper)`, each a numerator and denominator. The bounds are the nearest ancestors of the magnitude on either side, `0/1` and `1/0` standing in for the ends of the tree, negated and swapped around if `self` is negative, so `-3/7` gives `(-1/2, -3/7, -2/5)` and `-1` gives `(-1/0, -1/1, 0/1)`. `0` isn't on the tree, so all three of its ratios are `0/1`.
+            ///
+            /// The values strictly between the bounds are exactly those whose paths down the tree pass through `self`.
+            #[must_use]
+            pub fn bounds(self) -> (($int, $int), ($int, $int), ($int, $int)) {
+                let precision = self.precision();
+                if precision == 0 {
+                    // self.0 is either 0 or 1
+                    return match (self.abs().0, self.is_positive()) {
+                        (0, _) => ((0, 1), (0, 1), (0, 1)),
+                        (_, true) => ((0, 1), (1, 1), (1, 0)),
+                        (_, false) => ((-1, 0), (-1, 1), (0, 1)),
+                    };
+                }
+                let masked_bits = self.0 & ($bits::MAX >> ($width - precision));
+                let mut lower_num = 0;
+                let mut lower_denom = 1;
+                let mut mid_num = 1;
+                let mut mid_denom = 1;
+                let mut upper_num = 1;
+                let mut upper_denom = 0;
+                for i in 0..(precision) {
+                    if masked_bits & (1 << i) == 0 {
+                        upper_num = mid_num;
+                        upper_denom = mid_denom;
+                        mid_num += lower_num;
+                        mid_denom += lower_denom;
+                    } else {
+                        lower_num = mid_num;
+                        lower_denom = mid_denom;
+                        mid_num += upper_num;
+                        mid_denom += upper_denom;
+                    }
+                }
+                if self.is_positive() {
+                    (
+                        (lower_num, lower_denom),
+                        (mid_num, mid_denom),
+                        (upper_num, upper_denom),
+                    )
+                } else {
+                    // negating flips the interval around
+                    (
+                        (-upper_num, upper_denom),
+                        (-mid_num, mid_denom),
+                        (-lower_num, lower_denom),
+                    )
+                }
+            }
+
+            /// The exact decimal expansion, with at most `digits` digits after the point and any repeating digits in parentheses, like `-0.(142857)` for `-1/7`. If the digits neither end nor start repeating within `digits` digits, they are cut off there, followed by `...`.
+            #[must_use]
+            pub fn to_decimal_string(self, digits: usize) -> String {
+                let (num, denom) = self.to_fraction();
+                decimal_string(
+                    num < 0,
+                    num.unsigned_abs().into(),
+                    denom.unsigned_abs().into(),
+                    digits,
+                )
+            }
+
+            /// The path down the Farey tree to the magnitude of `self`, as `L` and `R` steps from the root, after a `-` if `self` is negative; `"-LLRR"` for `-3/7`. The path to `1` is empty, and `0`, which isn't on the tree, is `"0"`. Every fraction type writes the same path for the same value.
+            #[must_use]
+            pub fn to_path_string(self) -> String {
+                let path = path_string(self.ufrac_bits(), $max_depth);
+                if self.is_negative() && self.abs().0 != 0 {
+                    format!("-{path}")
+                } else {
+                    path
+                }
+            }
+
+            /// Follow a path of `L` and `R` steps down from the root, optionally after a `-`, as written by [`to_path_string`](Self::to_path_string).
+            ///
+            /// # Errors
+            #[doc = concat!("`FractionError::InvalidSyntax` if the path has anything but `L`s and `R`s, `FractionError::OutOfRange` if it leads past `MAX`, and `FractionError::PrecisionLoss` if it's otherwise more than ", $max_depth, " steps long")]
+            pub fn from_path_str(path: &str) -> Result<Self, FractionError> {
+                let (is_negative, bits) = parse_path(path, $width, $max_depth)?;
+                Ok(Self::from_ufrac_bits(bits, is_negative && bits != 0))
+            }
+
+            #[doc = concat!("The inverse of an `", stringify!($frac), "`, keeping its sign. `0` has no inverse, so it becomes `", stringify!($frac), "::MAX`.")]
+            #[must_use]
+            pub const fn invert(self) -> Self {
+                if self.abs().0 == 0 {
+                    Self::MAX
+                } else {
+                    let precision = self.precision();
+                    Self(
+                        (self.0 & !((1 << $max) - 1)) | (1 << precision) | (!self.0 & ((1 << precision) - 1)),
+                    )
+                }
+            }
+
+            #[doc = concat!("Construct an `", stringify!($frac), "` from a bit pattern")]
+            #[must_use]
+            pub const fn from_bits(bits: $bits) -> Self {
+                Self(bits)
+            }
+
+            #[doc = concat!("Get the bit pattern out of an `", stringify!($frac), "`")]
+            #[must_use]
+            pub const fn to_bits(self) -> $bits {
+                self.0
+            }
+
+            #[must_use]
+            pub const fn is_positive(self) -> bool {
+                self.0 >> $max == 0
+            }
+
+            #[must_use]
+            pub const fn is_negative(self) -> bool {
+                self.0 >> $max == 1
+            }
+
+            #[must_use]
+            pub const fn abs(self) -> Self {
+                Self(self.0 & ((1 << $max) - 1))
+            }
+
+            #[doc = concat!("Get the precision of a value. This will be a value from 0 to ", $max_depth, " representing how many steps down the Farey tree the fraction is.")]
+            /// If `self` is equal to `0` or `1`, this function will return `0`.
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation)]
+            pub const fn precision(self) -> $precision {
+                $precision::saturating_sub($max, self.abs().0.leading_zeros() as $precision)
+            }
+
+            #[doc = concat!("The next larger `", stringify!($frac), "`, like `f64::next_up`: from `0` that's the smallest positive value, and from the smallest negative one it's `0`. Returns `None` if called on `", stringify!($frac), "::MAX`.")]
+            #[must_use]
+            pub fn next_up(self) -> Option<Self> {
+                self.neighbour(true)
+            }
+
+            #[doc = concat!("The next smaller `", stringify!($frac), "`, like `f64::next_down`. Returns `None` if called on `-", stringify!($frac), "::MAX`.")]
+            #[must_use]
+            pub fn next_down(self) -> Option<Self> {
+                self.neighbour(false)
+            }
+
+            /// The deepest node that both `a` and `b` lie under, each counting as under itself, so the common ancestor of `-3/7` and `-2/5` is `-2/5`. Negative values hang from a mirror image of the tree rooted at `-1`, so this returns `None` if `a` and `b` have different signs, or if either is `0`, which isn't on the tree.
+            #[must_use]
+            pub fn common_ancestor(a: Self, b: Self) -> Option<Self> {
+                let (ancestor, _) = Self::ancestry(a, b)?;
+                Some(Self::from_ufrac_bits(ancestor, a.is_negative()))
+            }
+
+            /// How many parent and child steps apart `a` and `b` are: up from one to their [`common_ancestor`](Self::common_ancestor) and down to the other. Returns `None` if they have different signs, or if either is `0`.
+            #[must_use]
+            pub fn tree_distance(a: Self, b: Self) -> Option<$precision> {
+                let (_, distance) = Self::ancestry(a, b)?;
+                $precision::try_from(distance).ok()
+            }
+
+            /// The common ancestor of `a` and `b` in the `UFrac` bit layout, and their distance apart, if they're on the same tree.
+            fn ancestry(a: Self, b: Self) -> Option<(u128, u32)> {
+                if a.abs().0 == 0 || b.abs().0 == 0 || a.is_negative() != b.is_negative() {
+                    return None;
+                }
+                Some(common_ancestor(a.ufrac_bits(), b.ufrac_bits(), $max_depth))
+            }
+
+            fn neighbour(self, up: bool) -> Option<Self> {
+                let (is_negative, bits) =
+                    signed_neighbour(self.is_negative(), self.ufrac_bits(), $max_depth, up)?;
+                Some(Self::from_ufrac_bits(bits, is_negative))
+            }
+
+            /// The magnitude of `self` in the `UFrac` bit layout.
+            fn ufrac_bits(self) -> u128 {
+                ifrac_to_ufrac_bits(self.abs().0.into(), $max_depth)
+            }
+
+            /// Where `self` falls among all values in order: its magnitude in the `UFrac` bit layout, which sorts the same way as the values, negated if `self` is negative. Both zeros land on `0`.
+            #[allow(clippy::cast_possible_wrap)]
+            fn signed_index(self) -> i128 {
+                let index = self.ufrac_bits() as i128;
+                if self.is_negative() {
+                    -index
+                } else {
+                    index
+                }
+            }
+
+            /// The bit pattern with the sign bit cleared on `0`, so both zeros compare and hash alike.
+            const fn canonical_bits(self) -> $bits {
+                if self.abs().0 == 0 {
+                    0
+                } else {
+                    self.0
+                }
+            }
+
+            /// The value with a magnitude given in the `UFrac` bit layout, and the sign bit set if `is_negative`.
+            fn from_ufrac_bits(bits: u128, is_negative: bool) -> Self {
+                let (magnitude, _) = ufrac_to_ifrac_bits(bits, $max_depth);
+                #[allow(clippy::cast_possible_truncation)]
+                Self(magnitude as $bits | ($bits::from(is_negative) << $max))
+            }
+
+            #[doc = concat!("Create an `", stringify!($frac), "` approximating an IEEE half-precision float, given as its bit pattern, comparing with it in half precision.")]
+            ///
+            /// # Errors
+            /// If passed an infinite or `NaN` value
+            pub fn from_f16_bits(bits: u16) -> Result<Self, FractionError> {
+                Self::from_float(F16::from_bits(bits))
+            }
+
+            #[doc = concat!("Create an `", stringify!($frac), "` from a float, rounding it as `mode` says. Values past `", stringify!($frac), "::MAX` on either side round towards zero to `MAX` or its negative.")]
+            ///
+            /// # Errors
+            /// If passed an infinite or `NaN` value, and `FractionError::OutOfRange` when rounding away from zero a value past `MAX`
+            pub fn from_f64_rounded(value: f64, mode: RoundingMode) -> Result<Self, FractionError> {
+                FractionError::check_finite(value)?;
+                if value == 0.0 {
+                    return Ok(Self::ZERO);
+                }
+                let is_negative = value.is_sign_negative();
+                let bits = round(value.abs(), $max_depth, mode.for_magnitude(is_negative))
+                    .ok_or(FractionError::OutOfRange { max: $max })?;
+                Ok(Self::from_ufrac_bits(bits, is_negative && bits != 0))
+            }
+
+            /// Approximate a float by walking down the tree in its own precision.
+            fn from_float<F: Float>(value: F) -> Result<Self, FractionError> {
+                let float = value.to_f64();
+                FractionError::check_finite(float)?;
+                if float == 0.0 {
+                    return Ok(Self::ZERO);
+                }
+                let bits = descend(value.abs(), $max_depth);
+                Ok(Self::from_ufrac_bits(bits, float.is_sign_negative() && bits != 0))
+            }
+        }
+
+        impl TryFrom<$int> for $frac {
+            type Error = FractionError;
+            #[doc = concat!("Try to create an integer `", stringify!($frac), "`. Fails if passed a value whose magnitude is greater than ", $max, ".")]
+            fn try_from(value: $int) -> Result<Self, Self::Error> {
+                let (value, is_negative) = (value.unsigned_abs(), value.is_negative());
+                if value == 0 {
+                    Ok(Self::ZERO)
+                } else if value <= $max {
+                    Ok(Self(
+                        ($bits::MAX >> ($width - value)) | ($bits::from(is_negative) << $max),
+                    ))
+                } else {
+                    Err(FractionError::OutOfRange { max: $max })
+                }
+            }
+        }
+
+        impl TryFrom<f64> for $frac {
+            type Error = FractionError;
+            #[doc = concat!("Try to create an `", stringify!($frac), "` approximating a float. Fails if passed an infinite or `NaN` value.")]
+            fn try_from(value: f64) -> Result<Self, Self::Error> {
+                Self::from_float(value)
+            }
+        }
+
+        impl TryFrom<f32> for $frac {
+            type Error = FractionError;
+            #[doc = concat!("Try to create an `", stringify!($frac), "` approximating a float, comparing with it in single precision. Fails if passed an infinite or `NaN` value.")]
+            fn try_from(value: f32) -> Result<Self, Self::Error> {
+                Self::from_float(value)
+            }
+        }
+    )*};
+}
+
+signed_fracs! {
+    IFrac8(u8, i8, u8): 8 7 6 {
+        layout: "`0bs001_xxxx`" "`0bs1xx_xxxx`",
+        debug: "0b1001_1100",
+        MIN: 0b0100_0000 = "1/7",
+        GOLDEN_RATIO: 0b0101_0101 = "21/13, or 1.61538461538",
+        E: 0b0101_1011 = "19/7, or 2.71428571429",
+        PI: 0b0100_0111 = "13/4, or 3.25",
+        MAX: 0b0111_1111,
+    }
+    IFrac16(u16, i16, u16): 16 15 14 {
+        layout: "`0bs001_xxxx_xxxx_xxxx`" "`0bs1xx_xxxx_xxxx_xxxx`",
+        debug: "0b1000_0000_0001_1100",
+        MIN: 0x4000 = "1/15",
+        GOLDEN_RATIO: 0x5555 = "987/610, or 1.61803278689",
+        E: 0x185B = "193/71, or 2.71830985915",
+        PI: 0x0207 = "22/7, or 3.14285714286",
+        MAX: 0x7FFF,
+    }
+    IFrac32(u32, i32, u32): 32 31 30 {
+        layout: "`0bs001_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx`" "`0bs1xx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx`",
+        debug: "0b1000_0000_0000_0000_0000_0000_0001_1100",
+        MIN: 0x4000_0000 = "1/31",
+        GOLDEN_RATIO: 0x5555_5555 = "2178309/1346269, or 1.61803398875",
+        E: 0x6017_E85B = "49171/18089, or 2.71828182874",
+        PI: 0x03FF_FC07 = "355/113, or 3.14159292035",
+        MAX: 0x7FFF_FFFF,
+    }
+    IFrac64(u64, i64, u32): 64 63 62 {
+        layout: "`0bs001_xxxx_..._xxxx`" "`0bs1xx_xxxx_..._xxxx`",
+        debug: "0b1000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0001_1100",
+        MIN: 0x4000_0000_0000_0000 = "1/63",
+        GOLDEN_RATIO: 0x5555_5555_5555_5555 = "10610209857723/6557470319842, or 1.61803398875",
+        E: 0x0180_05FF_A017_E85B = "28245729/10391023, or 2.71828182846",
+        PI: 0x03FF_FC07 = "355/113, or 3.14159292035",
+        MAX: 0x7FFF_FFFF_FFFF_FFFF,
+    }
+}
+
+/// Conversions between a narrower signed width and each wider one, and into each wider one from the unsigned type of the same width as the narrow one.
+macro_rules! resize_conversions {
+    ($narrow:ident($narrow_bits:ident): $width:literal $max:literal $max_depth:literal, $lossy:ident => $($wide:ident($wide_bits:ident))*) => {$(
+        impl $wide {
+            #[doc = concat!("Convert to an `", stringify!($narrow), "`. For values with ", $max_depth, " or fewer bits of precision, this conversion is lossless. For values with ", $max, " or more bits of precision, this conversion truncates excess data.")]
+            ///
+            #[doc = concat!("If you would like to limit this to a lossless conversion, try `", stringify!($narrow), "::try_from`.")]
+            #[must_use]
+            pub fn $lossy(self) -> $narrow {
+                if let Ok(frac) = $narrow::try_from(self) {
+                    return frac;
+                }
+                #[allow(clippy::cast_possible_truncation)]
+                $narrow::from_bits(
+                    (self.0 & ((1 << $max_depth) - 1)) as $narrow_bits
+                        | (1 << $max_depth)
+                        | ($narrow_bits::from(self.is_negative()) << $max),
+                )
+            }
+        }
+
+        impl From<$narrow> for $wide {
+            fn from(value: $narrow) -> Self {
+                Self(
+                    $wide_bits::from(value.abs().to_bits())
+                        | ($wide_bits::from(value.is_negative()) << ($wide_bits::BITS - 1)),
+                )
+            }
+        }
+
+        impl TryFrom<$wide> for $narrow {
+            type Error = FractionError;
+            #[doc = concat!("Try to fit an `", stringify!($wide), "` into an `", stringify!($narrow), "`. Fails if passed a value whose magnitude is greater than ", $max, ", or one with ", $max, " or more bits of precision. If you would like to truncate the value instead, try `", stringify!($wide), "::", stringify!($lossy), "`.")]
+            fn try_from(value: $wide) -> Result<Self, Self::Error> {
+                let magnitude = value.abs().to_bits();
+                if ifrac_exceeds(magnitude.into(), $max) {
+                    return Err(FractionError::OutOfRange { max: $max });
+                }
+                if magnitude >> $max != 0 {
+                    return Err(FractionError::PrecisionLoss {
+                        bits_needed: u128::from(value.precision()) + 2,
+                    });
+                }
+                #[allow(clippy::cast_possible_truncation)]
+                Ok(Self(
+                    magnitude as $narrow_bits | ($narrow_bits::from(value.is_negative()) << $max),
+                ))
+            }
+        }
+
+        impl From<UFrac<$width>> for $wide {
+            fn from(value: UFrac<$width>) -> Self {
+                let (magnitude, _) = ufrac_to_ifrac_bits(u128::from(value.to_bits()), $max);
+                #[allow(clippy::cast_possible_truncation)]
+                Self(magnitude as $wide_bits)
+            }
+        }
+    )*};
+}
+
+resize_conversions!(IFrac8(u8): 8 7 6, to_ifrac8_lossy => IFrac16(u16) IFrac32(u32) IFrac64(u64));
+resize_conversions!(IFrac16(u16): 16 15 14, to_ifrac16_lossy => IFrac32(u32) IFrac64(u64));
+resize_conversions!(IFrac32(u32): 32 31 30, to_ifrac32_lossy => IFrac64(u64));
+
+impl<const BITS: u32> TryFrom<UFrac<BITS>> for IFrac8
+where
+    Width<BITS>: BitWidth,
+{
+    type Error = FractionError;
+    /// Try to fit a `UFrac` into an `IFrac8`. Fails if passed a value greater than 7, or one with 7 or more bits of precision. If you would like to truncate the value instead, try `UFrac::to_ifrac8_lossy`.
+    fn try_from(value: UFrac<BITS>) -> Result<Self, Self::Error> {
+        if ufrac_exceeds(value.to_bits().into(), BITS - 1, 7) {
+            return Err(FractionError::OutOfRange { max: 7 });
+        }
+        let (magnitude, precision) = ufrac_to_ifrac_bits(value.to_bits().into(), BITS - 1);
+        if precision > 6 {
+            return Err(FractionError::PrecisionLoss {
+                bits_needed: u128::from(precision) + 2,
+            });
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(Self(magnitude as u8))
+    }
+}
+
+/// Conversions into `f64` for the widths where every value gets its own `f64`, so the value can be recovered from it.
+macro_rules! float_conversions {
+    ($($frac:ident)*) => {$(
+        impl From<$frac> for f64 {
+            #[doc = concat!("The nearest `f64` to an `", stringify!($frac), "`. No two `", stringify!($frac), "`s round to the same `f64`.")]
+            fn from(value: $frac) -> Self {
+                value.to_f64()
+            }
+        }
+    )*};
+}
+
+float_conversions!(IFrac8 IFrac16);
@@ -1,4 +1,13 @@
+#[cfg(feature = "big")]
+mod big;
+mod fraction;
+mod ifrac16;
+mod ifrac32;
+mod ifrac64;
 mod ifrac8;
+mod ufrac;
+mod ufrac128;
 mod ufrac16;
 mod ufrac32;
+mod ufrac64;
 mod ufrac8;
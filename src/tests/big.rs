@@ -0,0 +1,287 @@
+use num_bigint::BigUint;
+
+use crate::{BigUFrac, FractionError, IFrac64, IFrac8, UFrac128, UFrac16, UFrac64, UFrac8};
+
+fn ratio(num: u64, denom: u64) -> (BigUint, BigUint) {
+    (BigUint::from(num), BigUint::from(denom))
+}
+
+#[test]
+fn to_fraction() {
+    assert_eq!(BigUFrac::ZERO.to_fraction(), ratio(0, 1));
+    assert_eq!(BigUFrac::one().to_fraction(), ratio(1, 1));
+    assert_eq!(BigUFrac::from(UFrac8::PI).to_fraction(), ratio(16, 5));
+    assert_eq!(
+        BigUFrac::from(UFrac8::PI).to_string(),
+        UFrac8::PI.to_string()
+    );
+    assert_eq!(format!("{:b}", BigUFrac::from(UFrac8::PI)), "11100001");
+    assert_eq!(
+        format!("{:?}", BigUFrac::from(UFrac8::PI)),
+        "BigUFrac(16/5 @ 0b1110_0001)"
+    );
+    assert_eq!(format!("{:#b}", BigUFrac::ZERO), "0b");
+    assert_eq!(format!("{:x}", BigUFrac::from(UFrac8::PI)), "e1");
+    assert_eq!(format!("{:#X}", BigUFrac::from(UFrac8::PI)), "0xE1");
+    assert_eq!(format!("{:o}", BigUFrac::from(UFrac8::PI)), "341");
+    let three_sevenths =
+        BigUFrac::from_fraction(&BigUint::from(3u32), &BigUint::from(7u32)).unwrap();
+    assert_eq!(format!("{three_sevenths:b}"), "00111");
+    assert_eq!(format!("{three_sevenths:x}"), "07");
+    assert_eq!(format!("{three_sevenths:#06o}"), "0o0007");
+    assert_eq!(format!("{:x}", BigUFrac::ZERO), "");
+}
+
+#[test]
+fn fixed_width_round_trip() {
+    for bits in 0..=u8::MAX {
+        let frac = UFrac8::from_bits(bits);
+        let big = BigUFrac::from(frac);
+        let (num, denom) = frac.to_fraction();
+        assert_eq!(big.to_fraction(), ratio(num.into(), denom.into()));
+        assert_eq!(UFrac8::try_from(big.clone()), Ok(frac));
+        assert_eq!(UFrac16::try_from(big), Ok(UFrac16::from(frac)));
+    }
+    for frac in [UFrac128::E, UFrac128::PI, UFrac128::MIN, UFrac128::MAX] {
+        let big = BigUFrac::from(frac);
+        assert_eq!(UFrac128::try_from(big.clone()), Ok(frac));
+        let (num, denom) = frac.to_fraction();
+        assert_eq!(
+            big.to_fraction(),
+            (BigUint::from(num), BigUint::from(denom))
+        );
+    }
+    assert_eq!(
+        UFrac8::try_from(BigUFrac::from(UFrac64::E)),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::from(UFrac64::E.precision()) + 1
+        })
+    );
+    assert_eq!(
+        UFrac8::try_from(BigUFrac::from(UFrac64::try_from(9).unwrap())),
+        Err(FractionError::OutOfRange { max: 8 })
+    );
+}
+
+#[test]
+fn signed_round_trip() {
+    for bits in 0..=u8::MAX {
+        let frac = IFrac8::from_bits(bits);
+        let big = BigUFrac::try_from(frac);
+        if frac.is_negative() && frac.abs() != IFrac8::ZERO {
+            assert_eq!(big, Err(FractionError::Negative));
+            continue;
+        }
+        let big = big.unwrap();
+        let (num, denom) = frac.to_fraction();
+        assert_eq!(
+            big.to_fraction(),
+            ratio(num.unsigned_abs().into(), denom.unsigned_abs().into())
+        );
+        assert_eq!(IFrac8::try_from(big.clone()), Ok(frac.abs()));
+        assert_eq!(IFrac64::try_from(big), Ok(IFrac64::from(frac.abs())));
+    }
+    for frac in [IFrac64::E, IFrac64::PI, IFrac64::MIN, IFrac64::MAX] {
+        let big = BigUFrac::try_from(frac).unwrap();
+        assert_eq!(IFrac64::try_from(big.clone()), Ok(frac));
+        assert_eq!(big.to_path_string(), frac.to_path_string());
+    }
+    assert_eq!(
+        IFrac8::try_from(BigUFrac::try_from(IFrac64::E).unwrap()),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::from(IFrac64::E.precision()) + 2
+        })
+    );
+    assert_eq!(
+        IFrac8::try_from(BigUFrac::try_from(IFrac64::try_from(8).unwrap()).unwrap()),
+        Err(FractionError::OutOfRange { max: 7 })
+    );
+}
+
+#[test]
+fn rationals_round_trip() {
+    let samples = [
+        ratio(0, 1),
+        ratio(1, 1),
+        ratio(355, 113),
+        ratio(1, 1000),
+        ratio(1_000_003, 999_983),
+        (
+            BigUint::from(10u32).pow(40) + 7u32,
+            BigUint::from(3u32).pow(70),
+        ),
+    ];
+    for (num, denom) in samples {
+        let frac = BigUFrac::from_fraction(&num, &denom).unwrap();
+        assert_eq!(frac.to_fraction(), (num, denom));
+    }
+    // fractions are reduced on the way through
+    let half = BigUFrac::from_fraction(&BigUint::from(2u32), &BigUint::from(4u32)).unwrap();
+    assert_eq!(half.to_fraction(), ratio(1, 2));
+    assert_eq!(
+        BigUFrac::from_fraction(&BigUint::from(1u32), &BigUint::ZERO),
+        Err(FractionError::Infinite)
+    );
+    // far deeper than any fixed width
+    assert_eq!(
+        BigUFrac::from_fraction(&BigUint::from(1u32), &BigUint::from(1000u32))
+            .unwrap()
+            .precision(),
+        999
+    );
+}
+
+#[test]
+fn tree() {
+    let one = BigUFrac::one();
+    assert_eq!(one.parent(), None);
+    assert_eq!(BigUFrac::ZERO.children(), None);
+    let (left, right) = one.children().unwrap();
+    assert_eq!(left.to_fraction(), ratio(1, 2));
+    assert_eq!(right.to_fraction(), ratio(2, 1));
+    assert_eq!(left.sibling(), Some(right.clone()));
+    assert_eq!(right.parent(), Some(one.clone()));
+    assert!(left < one && one < right && BigUFrac::ZERO < left);
+
+    // walk down past a word boundary and back up again
+    let mut node = one;
+    let mut path = vec![];
+    for step in 0..200 {
+        let (left, right) = node.children().unwrap();
+        assert!(left < node && node < right);
+        node = if step % 3 == 0 { right } else { left };
+        path.push(node.clone());
+    }
+    assert_eq!(node.precision(), 200);
+    assert_eq!(node.invert().invert(), node);
+    let (num, denom) = node.to_fraction();
+    assert_eq!(node.invert().to_fraction(), (denom, num));
+    while let Some(parent) = path.pop() {
+        assert_eq!(parent, node);
+        node = node.parent().unwrap();
+    }
+    assert_eq!(node, BigUFrac::one());
+}
+
+#[test]
+fn from_str() {
+    let parse = |text: &str| text.parse::<BigUFrac>().map(|frac| frac.to_fraction());
+    assert_eq!(parse("1 1/2"), Ok(ratio(3, 2)));
+    assert_eq!(parse("1000"), Ok(ratio(1000, 1)));
+    // far past what fits in 256 bits
+    let long = "0.5".to_owned() + &"0".repeat(100);
+    assert_eq!(parse(&long), Ok(ratio(1, 2)));
+    let (mut small, mut large) = (BigUint::from(1u32), BigUint::from(1u32));
+    for _ in 0..400 {
+        (small, large) = (large.clone(), small + large);
+    }
+    let frac: BigUFrac = format!("{small}/{large}").parse().unwrap();
+    assert_eq!(frac.precision(), 400);
+    assert_eq!(frac.to_fraction(), (small, large));
+    assert_eq!(parse("-1/2"), Err(FractionError::Negative));
+    assert_eq!(parse("1/2/3"), Err(FractionError::InvalidSyntax));
+}
+
+#[test]
+fn path_string() {
+    let frac = BigUFrac::from_fraction(&BigUint::from(3u32), &BigUint::from(7u32)).unwrap();
+    assert_eq!(frac.to_path_string(), "LLRR");
+    assert_eq!(BigUFrac::one().to_path_string(), "");
+    assert_eq!(BigUFrac::ZERO.to_path_string(), "0");
+    assert_eq!(BigUFrac::from_path_str("LLRR"), Ok(frac));
+    assert_eq!(BigUFrac::from_path_str("0"), Ok(BigUFrac::ZERO));
+    assert_eq!(BigUFrac::from_path_str("-R"), Err(FractionError::Negative));
+    assert_eq!(
+        BigUFrac::from_path_str("LR "),
+        Err(FractionError::InvalidSyntax)
+    );
+    // the same path as the fixed-width types, past a word boundary
+    let path = "RL".repeat(63) + "R";
+    assert_eq!(
+        UFrac128::from_path_str(&path).unwrap().to_path_string(),
+        path
+    );
+    let big = BigUFrac::from_path_str(&path).unwrap();
+    assert_eq!(big.to_path_string(), path);
+    assert_eq!(UFrac128::try_from(big), UFrac128::from_path_str(&path));
+}
+
+#[test]
+fn bounds() {
+    assert_eq!(
+        BigUFrac::from(UFrac8::PI).bounds(),
+        (ratio(3, 1), ratio(16, 5), ratio(13, 4))
+    );
+    assert_eq!(
+        BigUFrac::ZERO.bounds(),
+        (ratio(0, 1), ratio(0, 1), ratio(0, 1))
+    );
+    let big = |(num, denom): (u8, u8)| ratio(num.into(), denom.into());
+    for bits in 1..=u8::MAX {
+        let frac = UFrac8::from_bits(bits);
+        let (lower, mid, upper) = frac.bounds();
+        assert_eq!(
+            BigUFrac::from(frac).bounds(),
+            (big(lower), big(mid), big(upper))
+        );
+    }
+}
+
+#[test]
+fn common_ancestor() {
+    for (a, b) in [
+        (UFrac128::E, UFrac128::PI),
+        (UFrac128::E, UFrac128::from(UFrac8::E)),
+        (UFrac128::MIN, UFrac128::MAX),
+        (UFrac128::E, UFrac128::E),
+    ] {
+        let (big_a, big_b) = (BigUFrac::from(a), BigUFrac::from(b));
+        assert_eq!(
+            BigUFrac::common_ancestor(&big_a, &big_b),
+            UFrac128::common_ancestor(a, b).map(BigUFrac::from)
+        );
+        assert_eq!(
+            BigUFrac::tree_distance(&big_a, &big_b),
+            UFrac128::tree_distance(a, b).map(|distance| distance as usize)
+        );
+    }
+    assert_eq!(
+        BigUFrac::common_ancestor(&BigUFrac::ZERO, &BigUFrac::one()),
+        None
+    );
+}
+
+#[test]
+fn ancestors_and_path_from_root() {
+    assert_eq!(BigUFrac::ZERO.ancestors().count(), 0);
+    assert_eq!(BigUFrac::one().path_from_root().count(), 0);
+    for frac in (0..=u8::MAX).map(UFrac8::from_bits) {
+        let big = BigUFrac::from(frac);
+        assert!(big.ancestors().eq(frac.ancestors().map(BigUFrac::from)));
+        assert!(big.path_from_root().eq(frac
+            .path_from_root()
+            .map(|(node, direction)| (BigUFrac::from(node), direction))));
+    }
+    let e = BigUFrac::from(UFrac128::E);
+    assert_eq!(e.ancestors().count(), e.precision() + 1);
+    assert_eq!(e.path_from_root().last().map(|(node, _)| node), Some(e));
+}
+
+#[test]
+fn display() {
+    let frac: BigUFrac = "3/7".parse().unwrap();
+    assert_eq!(format!("{frac}"), "3/7");
+    assert_eq!(format!("{frac:.3}"), "0.429");
+    assert_eq!(format!("{frac:>8}"), "     3/7");
+    assert_eq!(format!("{frac:+}"), "+3/7");
+    assert_eq!(format!("{:#}", BigUFrac::from(UFrac8::PI)), "3 1/5");
+    assert_eq!(format!("{:.2}", BigUFrac::from(UFrac8::PI)), "3.20");
+    // the golden ratio, from Fibonacci numbers far past what fits in `u128`
+    let (mut small, mut large) = (BigUint::from(1u32), BigUint::from(1u32));
+    for _ in 0..400 {
+        (small, large) = (large.clone(), small + large);
+    }
+    let frac: BigUFrac = format!("{large}/{small}").parse().unwrap();
+    assert_eq!(format!("{frac:.20}"), "1.61803398874989484820");
+    assert_eq!(format!("{:.1}", BigUFrac::ZERO), "0.0");
+}
@@ -0,0 +1,63 @@
+use crate::{FareyFraction, UFrac16, UFrac32, UFrac64, UFrac8};
+
+/// Walk from the root down to a leaf, zigzagging, and check every step against the tree's invariants.
+fn walk_down<F: FareyFraction>() {
+    assert_eq!(F::ZERO.parent(), None);
+    assert_eq!(F::ONE.parent(), None);
+    assert_eq!(F::ZERO.children(), None);
+    assert_eq!(F::ONE.sibling(), None);
+    assert!(F::MIN.is_leaf());
+    assert!(F::MAX.is_leaf());
+    assert_eq!(F::MAX.invert(), F::MIN);
+    assert_eq!(F::ZERO.try_invert(), None);
+
+    let mut node = F::ONE;
+    let mut go_right = true;
+    while let Some((left, right)) = node.children() {
+        assert!(left < node && node < right);
+        assert_eq!(left.parent(), Some(node));
+        assert_eq!(right.parent(), Some(node));
+        assert_eq!(left.sibling(), Some(right));
+        assert_eq!(right.sibling(), Some(left));
+        assert_eq!(left.precision(), node.precision() + 1);
+        assert_eq!(left.invert(), right.invert().sibling().unwrap());
+        node = if go_right { right } else { left };
+        go_right = !go_right;
+    }
+    assert!(node.is_leaf());
+    assert_eq!(node.precision(), F::MAX_PRECISION);
+}
+
+/// Count the steps back up to the root using nothing but `parent`.
+fn depth<F: FareyFraction>(mut node: F) -> u32 {
+    let mut depth = 0;
+    while let Some(parent) = node.parent() {
+        node = parent;
+        depth += 1;
+    }
+    depth
+}
+
+#[test]
+fn walk_down_every_width() {
+    walk_down::<UFrac8>();
+    walk_down::<UFrac16>();
+    walk_down::<UFrac32>();
+    walk_down::<UFrac64>();
+}
+
+#[test]
+fn parent_undoes_children() {
+    for bits in 1..=u8::MAX {
+        let frac = UFrac8::from_bits(bits);
+        assert_eq!(depth(frac), frac.precision());
+        if let Some((left, right)) = frac.children() {
+            assert_eq!(left.parent(), Some(frac));
+            assert_eq!(right.parent(), Some(frac));
+        }
+    }
+    assert_eq!(UFrac8::from_bits(0b1100_0000).parent(), Some(UFrac8::ONE));
+    assert_eq!(depth(UFrac16::PI), UFrac16::PI.precision());
+    assert_eq!(depth(UFrac32::E), UFrac32::E.precision());
+    assert_eq!(depth(UFrac64::MAX), 63);
+}
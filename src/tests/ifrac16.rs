@@ -0,0 +1,156 @@
+use std::cmp::Ordering;
+
+use crate::{FractionError, IFrac16, IFrac8, UFrac8};
+
+#[test]
+fn to_fraction() {
+    assert_eq!(IFrac16::ONE.to_fraction(), (1, 1));
+    assert_eq!(IFrac16::from_bits(0b0010).to_fraction(), (1, 2));
+    assert_eq!(IFrac16::from_bits(0b0011).to_fraction(), (2, 1));
+    assert_eq!(IFrac16::from_bits(0b0001_0010).to_fraction(), (4, 7));
+    assert_eq!(IFrac16::from_bits(0b0001_0101).to_fraction(), (8, 5));
+    assert_eq!(IFrac16::MIN.to_fraction(), (1, 15));
+    assert_eq!(IFrac16::MAX.to_fraction(), (15, 1));
+    assert_eq!(IFrac16::GOLDEN_RATIO.to_fraction(), (987, 610));
+    assert_eq!(IFrac16::E.to_fraction(), (193, 71));
+    assert_eq!(IFrac16::PI.to_fraction(), (22, 7));
+    assert_eq!(IFrac16::try_from(-1.5).unwrap().to_fraction(), (-3, 2));
+}
+
+#[test]
+fn invert() {
+    assert_eq!(IFrac16::ZERO.invert(), IFrac16::MAX);
+    assert_eq!(IFrac16::ONE.invert(), IFrac16::ONE);
+    assert_eq!(
+        IFrac16::from_bits(0b0001_0010).invert().to_fraction(),
+        (7, 4)
+    );
+    assert_eq!(
+        IFrac16::try_from(-2).unwrap().invert().to_fraction(),
+        (-1, 2)
+    );
+    assert_eq!(IFrac16::MIN.invert(), IFrac16::MAX);
+    assert_eq!(IFrac16::MAX.invert(), IFrac16::MIN);
+}
+
+#[test]
+fn from_i16() {
+    for i in -15..=15 {
+        assert_eq!(IFrac16::try_from(i).unwrap().to_fraction(), (i, 1));
+    }
+    assert!(IFrac16::try_from(16).is_err());
+}
+
+#[test]
+fn from_f64() {
+    assert_eq!(IFrac16::try_from(1.0).unwrap().to_fraction(), (1, 1));
+    assert_eq!(IFrac16::try_from(-0.5).unwrap().to_fraction(), (-1, 2));
+    assert_eq!(IFrac16::try_from(1.618).unwrap().to_fraction(), (665, 411));
+    assert!(IFrac16::try_from(f64::NAN).is_err());
+}
+
+#[test]
+fn from_f32() {
+    assert_eq!(IFrac16::try_from(-1.5f32).unwrap().to_fraction(), (-3, 2));
+    assert_eq!(IFrac16::try_from(0.1f32).unwrap().to_fraction(), (1, 10));
+    assert!(IFrac16::try_from(f32::NEG_INFINITY).is_err());
+}
+
+#[test]
+fn from_f16_bits() {
+    assert_eq!(
+        IFrac16::from_f16_bits(0xbe00).unwrap().to_fraction(),
+        (-3, 2)
+    );
+    assert_eq!(
+        IFrac16::from_f16_bits(0x3555).unwrap().to_fraction(),
+        (1, 3)
+    );
+    assert_eq!(IFrac16::from_f16_bits(0x8000), Ok(IFrac16::ZERO));
+    assert_eq!(IFrac16::from_f16_bits(0xfe00), Err(FractionError::NaN));
+}
+
+#[test]
+fn ordering() {
+    assert_eq!(
+        IFrac16::try_from(2)
+            .unwrap()
+            .cmp(&IFrac16::try_from(3).unwrap()),
+        Ordering::Less
+    );
+    assert_eq!(
+        IFrac16::try_from(-4)
+            .unwrap()
+            .cmp(&IFrac16::try_from(-3).unwrap()),
+        Ordering::Less
+    );
+    assert_eq!(
+        IFrac16::try_from(2)
+            .unwrap()
+            .cmp(&IFrac16::try_from(1.9).unwrap()),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn ifrac8_conversions() {
+    for bits in 0..=255 {
+        let frac = IFrac8::from_bits(bits);
+        assert_eq!(IFrac16::from(frac).to_fraction(), {
+            let (num, denom) = frac.to_fraction();
+            (i16::from(num), i16::from(denom))
+        });
+        assert_eq!(IFrac8::try_from(IFrac16::from(frac)), Ok(frac));
+    }
+    assert_eq!(
+        IFrac8::try_from(IFrac16::PI),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::from(IFrac16::PI.precision()) + 2
+        })
+    );
+    assert_eq!(
+        IFrac8::try_from(IFrac16::try_from(-9).unwrap()),
+        Err(FractionError::OutOfRange { max: 7 })
+    );
+    assert_eq!(
+        IFrac8::try_from("15/2".parse::<IFrac16>().unwrap()),
+        Err(FractionError::OutOfRange { max: 7 })
+    );
+    assert_eq!(
+        IFrac16::PI.to_ifrac8_lossy(),
+        IFrac8::from_bits(0b0100_0111)
+    );
+    assert_eq!(IFrac16::MAX.to_ifrac8_lossy(), IFrac8::MAX);
+    assert_eq!(
+        IFrac16::try_from(-1.0 / 3.0).unwrap().to_ifrac8_lossy(),
+        IFrac8::try_from(-1.0 / 3.0).unwrap()
+    );
+}
+
+#[test]
+fn from_ufrac8() {
+    for bits in 0..=u8::MAX {
+        let unsigned = UFrac8::from_bits(bits);
+        let (num, denom) = IFrac16::from(unsigned).to_fraction();
+        let (expected_num, expected_denom) = unsigned.to_fraction();
+        assert_eq!(
+            (num, denom),
+            (expected_num.into(), expected_denom.into()),
+            "{unsigned:?}"
+        );
+    }
+}
+
+#[test]
+fn to_f64_is_injective() {
+    // skip the bit pattern of negative zero, which is the same value as zero
+    let mut floats: Vec<f64> = (0..=u16::MAX)
+        .filter(|&bits| bits != 0x8000)
+        .map(IFrac16::from_bits)
+        .map(f64::from)
+        .collect();
+    let len = floats.len();
+    floats.sort_by(f64::total_cmp);
+    floats.dedup();
+    assert_eq!(floats.len(), len);
+}
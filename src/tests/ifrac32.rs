@@ -0,0 +1,113 @@
+use std::cmp::Ordering;
+
+use crate::{FractionError, IFrac32, IFrac8, UFrac8};
+
+#[test]
+fn to_fraction() {
+    assert_eq!(IFrac32::ONE.to_fraction(), (1, 1));
+    assert_eq!(IFrac32::from_bits(0b0010).to_fraction(), (1, 2));
+    assert_eq!(IFrac32::from_bits(0b0011).to_fraction(), (2, 1));
+    assert_eq!(IFrac32::from_bits(0b0001_0010).to_fraction(), (4, 7));
+    assert_eq!(IFrac32::from_bits(0b0001_0101).to_fraction(), (8, 5));
+    assert_eq!(IFrac32::MIN.to_fraction(), (1, 31));
+    assert_eq!(IFrac32::MAX.to_fraction(), (31, 1));
+    assert_eq!(IFrac32::GOLDEN_RATIO.to_fraction(), (2_178_309, 1_346_269));
+    assert_eq!(IFrac32::E.to_fraction(), (49171, 18089));
+    assert_eq!(IFrac32::PI.to_fraction(), (355, 113));
+    assert_eq!(IFrac32::try_from(-1.5).unwrap().to_fraction(), (-3, 2));
+}
+
+#[test]
+fn invert() {
+    assert_eq!(IFrac32::ZERO.invert(), IFrac32::MAX);
+    assert_eq!(IFrac32::ONE.invert(), IFrac32::ONE);
+    assert_eq!(
+        IFrac32::from_bits(0b0001_0010).invert().to_fraction(),
+        (7, 4)
+    );
+    assert_eq!(
+        IFrac32::try_from(-2).unwrap().invert().to_fraction(),
+        (-1, 2)
+    );
+    assert_eq!(IFrac32::MIN.invert(), IFrac32::MAX);
+    assert_eq!(IFrac32::MAX.invert(), IFrac32::MIN);
+}
+
+#[test]
+fn from_i32() {
+    for i in -31..=31 {
+        assert_eq!(IFrac32::try_from(i).unwrap().to_fraction(), (i, 1));
+    }
+    assert!(IFrac32::try_from(32).is_err());
+}
+
+#[test]
+fn from_f64() {
+    assert_eq!(IFrac32::try_from(1.0).unwrap().to_fraction(), (1, 1));
+    assert_eq!(IFrac32::try_from(-0.5).unwrap().to_fraction(), (-1, 2));
+    assert_eq!(IFrac32::try_from(1.618).unwrap().to_fraction(), (809, 500));
+    assert!(IFrac32::try_from(f64::NAN).is_err());
+}
+
+#[test]
+fn ordering() {
+    assert_eq!(
+        IFrac32::try_from(2)
+            .unwrap()
+            .cmp(&IFrac32::try_from(3).unwrap()),
+        Ordering::Less
+    );
+    assert_eq!(
+        IFrac32::try_from(-4)
+            .unwrap()
+            .cmp(&IFrac32::try_from(-3).unwrap()),
+        Ordering::Less
+    );
+    assert_eq!(
+        IFrac32::try_from(2)
+            .unwrap()
+            .cmp(&IFrac32::try_from(1.9).unwrap()),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn ifrac8_conversions() {
+    for bits in 0..=255 {
+        let frac = IFrac8::from_bits(bits);
+        assert_eq!(IFrac32::from(frac).to_fraction(), {
+            let (num, denom) = frac.to_fraction();
+            (i32::from(num), i32::from(denom))
+        });
+        assert_eq!(IFrac8::try_from(IFrac32::from(frac)), Ok(frac));
+    }
+    assert_eq!(
+        IFrac8::try_from(IFrac32::PI),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::from(IFrac32::PI.precision()) + 2
+        })
+    );
+    assert_eq!(
+        IFrac32::PI.to_ifrac8_lossy(),
+        IFrac8::from_bits(0b0100_0111)
+    );
+    assert_eq!(IFrac32::MAX.to_ifrac8_lossy(), IFrac8::MAX);
+    assert_eq!(
+        IFrac32::try_from(-1.0 / 3.0).unwrap().to_ifrac8_lossy(),
+        IFrac8::try_from(-1.0 / 3.0).unwrap()
+    );
+}
+
+#[test]
+fn from_ufrac8() {
+    for bits in 0..=u8::MAX {
+        let unsigned = UFrac8::from_bits(bits);
+        let (num, denom) = IFrac32::from(unsigned).to_fraction();
+        let (expected_num, expected_denom) = unsigned.to_fraction();
+        assert_eq!(
+            (num, denom),
+            (expected_num.into(), expected_denom.into()),
+            "{unsigned:?}"
+        );
+    }
+}
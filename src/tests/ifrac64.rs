@@ -0,0 +1,161 @@
+use std::cmp::Ordering;
+
+use crate::{FractionError, IFrac16, IFrac32, IFrac64, IFrac8, UFrac16, UFrac32, UFrac8};
+
+#[test]
+fn to_fraction() {
+    assert_eq!(IFrac64::ONE.to_fraction(), (1, 1));
+    assert_eq!(IFrac64::from_bits(0b0010).to_fraction(), (1, 2));
+    assert_eq!(IFrac64::from_bits(0b0011).to_fraction(), (2, 1));
+    assert_eq!(IFrac64::from_bits(0b0001_0010).to_fraction(), (4, 7));
+    assert_eq!(IFrac64::from_bits(0b0001_0101).to_fraction(), (8, 5));
+    assert_eq!(IFrac64::MIN.to_fraction(), (1, 63));
+    assert_eq!(IFrac64::MAX.to_fraction(), (63, 1));
+    assert_eq!(
+        IFrac64::GOLDEN_RATIO.to_fraction(),
+        (10_610_209_857_723, 6_557_470_319_842)
+    );
+    assert_eq!(IFrac64::E.to_fraction(), (28_245_729, 10_391_023));
+    assert_eq!(IFrac64::PI.to_fraction(), (355, 113));
+    assert_eq!(IFrac64::try_from(-1.5).unwrap().to_fraction(), (-3, 2));
+}
+
+#[test]
+fn invert() {
+    assert_eq!(IFrac64::ZERO.invert(), IFrac64::MAX);
+    assert_eq!(IFrac64::ONE.invert(), IFrac64::ONE);
+    assert_eq!(
+        IFrac64::from_bits(0b0001_0010).invert().to_fraction(),
+        (7, 4)
+    );
+    assert_eq!(
+        IFrac64::try_from(-2).unwrap().invert().to_fraction(),
+        (-1, 2)
+    );
+    assert_eq!(IFrac64::MIN.invert(), IFrac64::MAX);
+    assert_eq!(IFrac64::MAX.invert(), IFrac64::MIN);
+}
+
+#[test]
+fn from_i64() {
+    for i in -63..=63 {
+        assert_eq!(IFrac64::try_from(i).unwrap().to_fraction(), (i, 1));
+    }
+    assert!(IFrac64::try_from(64).is_err());
+}
+
+#[test]
+fn from_f64() {
+    assert_eq!(IFrac64::try_from(1.0).unwrap().to_fraction(), (1, 1));
+    assert_eq!(IFrac64::try_from(-0.5).unwrap().to_fraction(), (-1, 2));
+    assert_eq!(IFrac64::try_from(1.618).unwrap().to_fraction(), (809, 500));
+    assert!(IFrac64::try_from(f64::NAN).is_err());
+}
+
+#[test]
+fn ordering() {
+    assert_eq!(
+        IFrac64::try_from(2)
+            .unwrap()
+            .cmp(&IFrac64::try_from(3).unwrap()),
+        Ordering::Less
+    );
+    assert_eq!(
+        IFrac64::try_from(-4)
+            .unwrap()
+            .cmp(&IFrac64::try_from(-3).unwrap()),
+        Ordering::Less
+    );
+    assert_eq!(
+        IFrac64::try_from(2)
+            .unwrap()
+            .cmp(&IFrac64::try_from(1.9).unwrap()),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn ifrac8_conversions() {
+    for bits in 0..=255 {
+        let frac = IFrac8::from_bits(bits);
+        assert_eq!(IFrac64::from(frac).to_fraction(), {
+            let (num, denom) = frac.to_fraction();
+            (i64::from(num), i64::from(denom))
+        });
+        assert_eq!(IFrac8::try_from(IFrac64::from(frac)), Ok(frac));
+    }
+    assert_eq!(
+        IFrac8::try_from(IFrac64::PI),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::from(IFrac64::PI.precision()) + 2
+        })
+    );
+    assert_eq!(
+        IFrac64::PI.to_ifrac8_lossy(),
+        IFrac8::from_bits(0b0100_0111)
+    );
+    assert_eq!(IFrac64::MAX.to_ifrac8_lossy(), IFrac8::MAX);
+    assert_eq!(
+        IFrac64::try_from(-1.0 / 3.0).unwrap().to_ifrac8_lossy(),
+        IFrac8::try_from(-1.0 / 3.0).unwrap()
+    );
+}
+
+#[test]
+fn wide_conversions() {
+    let third = IFrac32::try_from(1.0 / 3.0).unwrap();
+    assert_eq!(IFrac64::from(third).to_fraction(), (1, 3));
+    assert_eq!(IFrac32::try_from(IFrac64::from(third)), Ok(third));
+    assert_eq!(
+        IFrac16::try_from(IFrac64::E),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::from(IFrac64::E.precision()) + 2
+        })
+    );
+    assert_eq!(IFrac64::E.to_ifrac16_lossy().to_fraction(), (492, 181));
+    assert_eq!(IFrac64::from(IFrac16::MIN).to_fraction(), (1, 15));
+    assert_eq!(IFrac64::E.to_ifrac32_lossy(), IFrac32::E);
+}
+
+#[test]
+fn from_ufrac8() {
+    for bits in 0..=u8::MAX {
+        let unsigned = UFrac8::from_bits(bits);
+        let (num, denom) = IFrac64::from(unsigned).to_fraction();
+        let (expected_num, expected_denom) = unsigned.to_fraction();
+        assert_eq!(
+            (num, denom),
+            (expected_num.into(), expected_denom.into()),
+            "{unsigned:?}"
+        );
+    }
+}
+
+#[test]
+fn from_wider_ufracs() {
+    assert_eq!(IFrac64::from(UFrac32::PI).to_fraction(), {
+        let (num, denom) = UFrac32::PI.to_fraction();
+        (num.into(), denom.into())
+    });
+    assert_eq!(IFrac64::from(UFrac16::E).to_fraction(), {
+        let (num, denom) = UFrac16::E.to_fraction();
+        (num.into(), denom.into())
+    });
+    assert_eq!(IFrac64::from(UFrac32::MAX), IFrac64::try_from(32).unwrap());
+    assert_eq!(IFrac32::from(UFrac16::MAX), IFrac32::try_from(16).unwrap());
+    assert_eq!(IFrac32::from(UFrac16::MIN).to_fraction(), (1, 16));
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn to_float() {
+    let frac = IFrac64::from_bits(0xd554_6555_dd07_c354);
+    let (num, den) = frac.to_fraction();
+    assert_eq!((num, den), (-149_707_338_457, 391_852_669_691));
+    // both fit in an f64, but rounding them to f32 before dividing lands one ulp off
+    assert_eq!(frac.to_f64().to_bits(), (num as f64 / den as f64).to_bits());
+    assert_eq!(frac.to_f32().to_bits(), 3_200_490_513);
+    assert_ne!(frac.to_f32().to_bits(), (num as f32 / den as f32).to_bits());
+    assert_eq!(IFrac64::MIN.to_f64().to_bits(), (1.0f64 / 63.0).to_bits());
+    assert_eq!(IFrac64::from_bits(u64::MAX).to_f32().to_bits(), (-63.0f32).to_bits());
+}
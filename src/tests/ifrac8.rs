@@ -1,6 +1,9 @@
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeSet, HashSet},
+};
 
-use crate::IFrac8;
+use crate::{FractionError, IFrac8, UFrac16, UFrac32, UFrac64, UFrac8};
 
 #[test]
 fn to_fraction() {
@@ -40,7 +43,13 @@ fn from_f64() {
     assert_eq!(IFrac8::try_from(1.0).unwrap().to_fraction(), (1, 1));
     assert_eq!(IFrac8::try_from(0.5).unwrap().to_fraction(), (1, 2));
     println!("{}", IFrac8::try_from(1.618).unwrap());
-    assert_eq!(IFrac8::try_from(1.618).unwrap().to_fraction(), (11, 7));
+    assert_eq!(IFrac8::try_from(1.618).unwrap().to_fraction(), (21, 13));
+    // `13/2` is too deep for an `IFrac8`, so these round to the nodes either side of it
+    assert_eq!(IFrac8::try_from(6.6f32), Ok(IFrac8::MAX));
+    assert_eq!(IFrac8::try_from(-6.4f32).unwrap().to_fraction(), (-6, 1));
+    assert_eq!(IFrac8::try_from(6.9), Ok(IFrac8::MAX));
+    assert!(IFrac8::try_from(f64::NAN).is_err());
+    assert!(IFrac8::try_from(f64::INFINITY).is_err());
 }
 
 #[test]
@@ -99,3 +108,363 @@ fn ordering() {
         Ordering::Greater
     );
 }
+
+#[test]
+fn ordering_matches_value() {
+    for a in (0..=u8::MAX).map(IFrac8::from_bits) {
+        for b in (0..=u8::MAX).map(IFrac8::from_bits) {
+            let ((a_num, a_den), (b_num, b_den)) = (a.to_fraction(), b.to_fraction());
+            assert_eq!(
+                a.cmp(&b),
+                (i16::from(a_num) * i16::from(b_den)).cmp(&(i16::from(b_num) * i16::from(a_den))),
+                "{a:?} vs {b:?}"
+            );
+        }
+    }
+    assert_eq!(IFrac8::ZERO.cmp(&IFrac8::ONE), Ordering::Less);
+    assert!(IFrac8::try_from(-1).unwrap() < IFrac8::ZERO);
+    assert!(IFrac8::try_from(-2).unwrap() < IFrac8::try_from(-1).unwrap());
+}
+
+#[test]
+fn invert_keeps_sign() {
+    assert_eq!(
+        IFrac8::try_from(-2).unwrap().invert().to_fraction(),
+        (-1, 2)
+    );
+    assert_eq!(
+        IFrac8::try_from(-0.25).unwrap().invert().to_fraction(),
+        (-4, 1)
+    );
+    assert_eq!(IFrac8::try_from(7).unwrap(), IFrac8::MAX);
+}
+
+#[test]
+fn ufrac_conversions() {
+    for bits in 0..=u8::MAX {
+        let unsigned = UFrac8::from_bits(bits);
+        let (num, denom) = unsigned.to_fraction();
+        match IFrac8::try_from(unsigned) {
+            Ok(signed) => {
+                let (signed_num, signed_denom) = signed.to_fraction();
+                assert_eq!(
+                    (i16::from(signed_num), i16::from(signed_denom)),
+                    (i16::from(num), i16::from(denom)),
+                    "{unsigned:?}"
+                );
+                assert_eq!(unsigned.to_ifrac8_lossy(), signed);
+                assert_eq!(UFrac8::try_from(signed), Ok(unsigned));
+            }
+            Err(error) if u16::from(num) > 7 * u16::from(denom) => {
+                assert_eq!(error, FractionError::OutOfRange { max: 7 });
+            }
+            Err(error) => {
+                assert_eq!(unsigned.precision(), 7);
+                assert_eq!(error, FractionError::PrecisionLoss { bits_needed: 9 });
+            }
+        }
+
+        let signed = IFrac8::from_bits(bits);
+        if signed.is_negative() && signed != IFrac8::from_bits(0b1000_0000) {
+            assert_eq!(UFrac8::try_from(signed), Err(FractionError::Negative));
+            assert_eq!(UFrac64::try_from(signed), Err(FractionError::Negative));
+        } else {
+            let (num, denom) = signed.to_fraction();
+            let expected = (num.unsigned_abs(), denom.unsigned_abs());
+            assert_eq!(UFrac8::try_from(signed).unwrap().to_fraction(), expected);
+            let (wide_num, wide_denom) = UFrac16::try_from(signed).unwrap().to_fraction();
+            assert_eq!(
+                (wide_num, wide_denom),
+                (expected.0.into(), expected.1.into())
+            );
+            let (wide_num, wide_denom) = UFrac32::try_from(signed).unwrap().to_fraction();
+            assert_eq!(
+                (wide_num, wide_denom),
+                (expected.0.into(), expected.1.into())
+            );
+            let (wide_num, wide_denom) = UFrac64::try_from(signed).unwrap().to_fraction();
+            assert_eq!(
+                (wide_num, wide_denom),
+                (expected.0.into(), expected.1.into())
+            );
+        }
+    }
+}
+
+#[test]
+fn ufrac_lossy_conversions() {
+    // the lossy conversion keeps the first six steps of the path
+    for bits in 0..=u16::MAX {
+        let unsigned = UFrac16::from_bits(bits);
+        let ancestor = if unsigned.precision() > 6 {
+            UFrac16::from_bits((bits & 0b1111_1100_0000_0000) | 0b10_0000_0000)
+        } else {
+            unsigned
+        };
+        assert_eq!(
+            unsigned.to_ifrac8_lossy(),
+            IFrac8::try_from(ancestor).unwrap(),
+            "{unsigned:?}"
+        );
+    }
+    assert_eq!(UFrac8::MAX.to_ifrac8_lossy(), IFrac8::MAX);
+    assert_eq!(UFrac8::MIN.to_ifrac8_lossy(), IFrac8::MIN);
+    assert_eq!(UFrac16::PI.to_ifrac8_lossy().to_fraction(), (13, 4));
+    assert_eq!(UFrac32::E.to_ifrac8_lossy().to_fraction(), (19, 7));
+    assert_eq!(UFrac64::MAX.to_ifrac8_lossy(), IFrac8::MAX);
+    assert_eq!(IFrac8::try_from(UFrac64::ONE), Ok(IFrac8::ONE));
+    assert_eq!(
+        IFrac8::try_from(UFrac64::PI),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::from(UFrac64::PI.precision()) + 2
+        })
+    );
+}
+
+#[test]
+fn from_f64_rounded() {
+    use crate::RoundingMode::{Ceil, Floor, Nearest, TowardZero};
+    use std::f64::consts::PI;
+
+    let round = |value, mode| IFrac8::from_f64_rounded(value, mode).unwrap().to_fraction();
+    assert_eq!(round(PI, Floor), (3, 1));
+    assert_eq!(round(PI, Ceil), (13, 4));
+    assert_eq!(round(PI, TowardZero), (3, 1));
+    assert_eq!(round(-PI, Floor), (-13, 4));
+    assert_eq!(round(-PI, Ceil), (-3, 1));
+    assert_eq!(round(-PI, TowardZero), (-3, 1));
+    assert_eq!(round(-PI, Nearest), (-13, 4));
+    assert_eq!(round(-100.0, Ceil), (-7, 1));
+    // a negative value rounding to `0` gives the plain zero bit pattern, without the sign
+    for mode in [Nearest, Ceil, TowardZero] {
+        assert_eq!(IFrac8::from_f64_rounded(-1e-30, mode).unwrap().to_bits(), 0);
+    }
+    assert_eq!(IFrac8::from_f64_rounded(-0.0, Floor).unwrap().to_bits(), 0);
+    assert_eq!(IFrac8::try_from(-1e-30).unwrap().to_bits(), 0);
+    assert_eq!(IFrac8::try_from(-1e-30f32).unwrap().to_bits(), 0);
+    assert_eq!(
+        IFrac8::from_f64_rounded(-100.0, Floor),
+        Err(FractionError::OutOfRange { max: 7 })
+    );
+    assert_eq!(
+        IFrac8::from_f64_rounded(f64::INFINITY, Floor),
+        Err(FractionError::Infinite)
+    );
+}
+
+#[test]
+fn from_str() {
+    let parse = |text: &str| text.parse::<IFrac8>().map(IFrac8::to_fraction);
+    assert_eq!(parse("-2/5"), Ok((-2, 5)));
+    assert_eq!(parse("-1 1/2"), Ok((-3, 2)));
+    assert_eq!(parse("-0.75"), Ok((-3, 4)));
+    assert_eq!(parse("7"), Ok((7, 1)));
+    assert_eq!(parse("-0"), Ok((0, 1)));
+    assert_eq!("-0".parse(), Ok(IFrac8::ZERO));
+    assert_eq!(parse("-8"), Err(FractionError::OutOfRange { max: 7 }));
+    // too long for 256 bits
+    let huge = format!("1{}", "0".repeat(80));
+    assert_eq!(
+        parse(&format!("-{huge}")),
+        Err(FractionError::OutOfRange { max: 7 })
+    );
+    assert_eq!(
+        parse(&format!("-1/{huge}")),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::MAX
+        })
+    );
+    assert_eq!(
+        parse("1/8"),
+        Err(FractionError::PrecisionLoss { bits_needed: 9 })
+    );
+    for bits in 0..=u8::MAX {
+        let frac = IFrac8::from_bits(bits);
+        if let Ok(parsed) = frac.to_string().parse::<IFrac8>() {
+            assert_eq!(parsed.to_fraction(), frac.to_fraction());
+        } else {
+            panic!("{frac} didn't parse");
+        }
+    }
+}
+
+#[test]
+fn display() {
+    let parse = |text: &str| text.parse::<IFrac8>().unwrap();
+    assert_eq!(parse("-2/5").to_string(), "-2/5");
+    assert_eq!(format!("{:+}", parse("2/5")), "+2/5");
+    assert_eq!(format!("{:+}", parse("-2/5")), "-2/5");
+    assert_eq!(format!("{:+}", IFrac8::ZERO), "+0/1");
+    assert_eq!(format!("{:#}", parse("-7/3")), "-2 1/3");
+    assert_eq!(format!("{:+#}", parse("7/3")), "+2 1/3");
+    assert_eq!(format!("{:.3}", parse("-2/3")), "-0.667");
+    assert_eq!(format!("{:+.1}", parse("1/4")), "+0.2");
+    assert_eq!(format!("{:07.2}", parse("-3/4")), "-000.75");
+    assert_eq!(parse("-1/3").to_decimal_string(5), "-0.(3)");
+    assert_eq!(parse("-5").to_decimal_string(5), "-5");
+}
+
+#[test]
+fn path_string() {
+    let parse = |text: &str| text.parse::<IFrac8>().unwrap();
+    assert_eq!(parse("-3/7").to_path_string(), "-LLRR");
+    assert_eq!(parse("3/7").to_path_string(), "LLRR");
+    assert_eq!(IFrac8::ZERO.to_path_string(), "0");
+    assert_eq!(parse("-1").to_path_string(), "-");
+    assert_eq!(IFrac8::from_path_str("-LLRR"), Ok(parse("-3/7")));
+    assert_eq!(IFrac8::from_path_str("-0"), Ok(IFrac8::ZERO));
+    assert_eq!(
+        IFrac8::from_path_str("LLLLLLL"),
+        Err(FractionError::PrecisionLoss { bits_needed: 9 })
+    );
+    for bits in 0..=u8::MAX {
+        let frac = IFrac8::from_bits(bits);
+        let path = frac.to_path_string();
+        let parsed = IFrac8::from_path_str(&path).unwrap();
+        assert_eq!(parsed.to_fraction(), frac.to_fraction());
+        // the path is the same as the unsigned type's for the same magnitude
+        let magnitude = UFrac8::from_path_str(path.trim_start_matches('-')).unwrap();
+        let (num, den) = frac.to_fraction();
+        assert_eq!(
+            magnitude.to_fraction(),
+            (num.unsigned_abs(), den.unsigned_abs())
+        );
+    }
+}
+
+#[test]
+fn bit_formats() {
+    let frac: IFrac8 = "-3/7".parse().unwrap();
+    assert_eq!(format!("{frac:?}"), "IFrac8(-3/7 @ 0b1001_1100)");
+    assert_eq!(format!("{frac:b}"), "10011100");
+    assert_eq!(format!("{frac:x}"), "9c");
+    assert_eq!(format!("{frac:#X}"), "0x9C");
+    assert_eq!(format!("{frac:o}"), "234");
+    assert_eq!(format!("{:#010b}", IFrac8::ONE), "0b00000001");
+}
+
+#[test]
+fn next_up_and_down() {
+    // every pattern but negative zero, from `-MAX` up to `MAX`
+    let mut values: Vec<IFrac8> = (0..=u8::MAX)
+        .filter(|&bits| bits != 0b1000_0000)
+        .map(IFrac8::from_bits)
+        .collect();
+    values.sort();
+    for pair in values.windows(2) {
+        assert_eq!(pair[0].next_up(), Some(pair[1]));
+        assert_eq!(pair[1].next_down(), Some(pair[0]));
+    }
+    assert_eq!(values.len(), 255);
+    assert_eq!(IFrac8::MAX.next_up(), None);
+    assert_eq!(values[0].next_down(), None);
+    assert_eq!(IFrac8::ZERO.next_up().unwrap().to_fraction(), (1, 7));
+    assert_eq!(IFrac8::ZERO.next_down().unwrap().to_fraction(), (-1, 7));
+    assert_eq!(
+        IFrac8::from_bits(0b1000_0000)
+            .next_up()
+            .unwrap()
+            .to_fraction(),
+        (1, 7)
+    );
+}
+
+#[test]
+fn bounds() {
+    assert_eq!(
+        "-3/7".parse::<IFrac8>().unwrap().bounds(),
+        ((-1, 2), (-3, 7), (-2, 5))
+    );
+    assert_eq!(IFrac8::ONE.bounds(), ((0, 1), (1, 1), (1, 0)));
+    assert_eq!(
+        IFrac8::from_bits(0b1000_0001).bounds(),
+        ((-1, 0), (-1, 1), (0, 1))
+    );
+    assert_eq!(IFrac8::ZERO.bounds(), ((0, 1), (0, 1), (0, 1)));
+    // every positive value's bounds match the same node's as a `UFrac8`, and negating it mirrors them
+    let signed = |(num, den): (u8, u8)| (i8::try_from(num).unwrap(), i8::try_from(den).unwrap());
+    for bits in 1..0b1000_0000 {
+        let positive = IFrac8::from_bits(bits);
+        let (lower, mid, upper) = positive.bounds();
+        let (ufrac_lower, ufrac_mid, ufrac_upper) = UFrac8::try_from(positive).unwrap().bounds();
+        assert_eq!(
+            (lower, mid, upper),
+            (signed(ufrac_lower), signed(ufrac_mid), signed(ufrac_upper))
+        );
+        assert_eq!(
+            IFrac8::from_bits(bits | 0b1000_0000).bounds(),
+            ((-upper.0, upper.1), (-mid.0, mid.1), (-lower.0, lower.1))
+        );
+    }
+}
+
+#[test]
+fn common_ancestor() {
+    let (a, b) = (
+        "-3/7".parse::<IFrac8>().unwrap(),
+        "-2/5".parse::<IFrac8>().unwrap(),
+    );
+    assert_eq!(IFrac8::common_ancestor(a, b), Some(b));
+    assert_eq!(IFrac8::tree_distance(a, b), Some(1));
+    assert_eq!(IFrac8::common_ancestor(a, b.abs()), None);
+    assert_eq!(IFrac8::tree_distance(IFrac8::ZERO, b), None);
+    // positive pairs match `UFrac8`, and negative pairs mirror them
+    let negate = |frac: IFrac8| IFrac8::from_bits(frac.to_bits() | 0b1000_0000);
+    for a in (1..0b1000_0000).map(IFrac8::from_bits) {
+        for b in (1..0b1000_0000).map(IFrac8::from_bits) {
+            let ancestor = IFrac8::common_ancestor(a, b).unwrap();
+            let ufrac = |frac: IFrac8| UFrac8::try_from(frac).unwrap();
+            assert_eq!(
+                Some(ufrac(ancestor)),
+                UFrac8::common_ancestor(ufrac(a), ufrac(b))
+            );
+            assert_eq!(
+                IFrac8::tree_distance(a, b).map(u32::from),
+                UFrac8::tree_distance(ufrac(a), ufrac(b))
+            );
+            assert_eq!(
+                IFrac8::common_ancestor(negate(a), negate(b)),
+                Some(negate(ancestor))
+            );
+            assert_eq!(
+                IFrac8::tree_distance(negate(a), negate(b)),
+                IFrac8::tree_distance(a, b)
+            );
+        }
+    }
+}
+
+#[test]
+fn negative_zero_is_zero() {
+    let negative_zero = IFrac8::from_bits(0b1000_0000);
+    assert_eq!(negative_zero, IFrac8::ZERO);
+    assert_eq!(negative_zero.cmp(&IFrac8::ZERO), Ordering::Equal);
+
+    let ordered: BTreeSet<_> = [IFrac8::ZERO, negative_zero, IFrac8::ONE].into();
+    assert_eq!(ordered.len(), 2);
+    let hashed: HashSet<_> = [IFrac8::ZERO, negative_zero, IFrac8::ONE].into();
+    assert_eq!(hashed.len(), 2);
+    assert!(hashed.contains(&negative_zero));
+}
+
+#[test]
+fn to_float() {
+    assert_eq!(IFrac8::ZERO.to_f64().to_bits(), 0.0f64.to_bits());
+    assert_eq!(IFrac8::from_bits(0x80).to_f32().to_bits(), 0.0f32.to_bits());
+    assert_eq!(IFrac8::MAX.to_f32().to_bits(), 7.0f32.to_bits());
+    assert_eq!(IFrac8::from_bits(0xFF).to_f64().to_bits(), (-7.0f64).to_bits());
+    assert_eq!(f64::from(IFrac8::PI).to_bits(), (13.0f64 / 4.0).to_bits());
+    // the numerator and denominator are exact in either float, so dividing them directly rounds correctly too
+    for bits in 0..=u8::MAX {
+        let frac = IFrac8::from_bits(bits);
+        let (num, den) = frac.to_fraction();
+        assert_eq!(
+            frac.to_f64().to_bits(),
+            (f64::from(num) / f64::from(den)).to_bits()
+        );
+        assert_eq!(
+            frac.to_f32().to_bits(),
+            (f32::from(num) / f32::from(den)).to_bits()
+        );
+    }
+}
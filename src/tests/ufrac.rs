@@ -0,0 +1,115 @@
+use std::mem::size_of;
+
+use crate::{FractionError, UFrac, UFrac16, UFrac32, UFrac64, UFrac8};
+
+#[test]
+fn backing_integers() {
+    assert_eq!(size_of::<UFrac<2>>(), 1);
+    assert_eq!(size_of::<UFrac8>(), 1);
+    assert_eq!(size_of::<UFrac<12>>(), 2);
+    assert_eq!(size_of::<UFrac<24>>(), 4);
+    assert_eq!(size_of::<UFrac<33>>(), 8);
+    assert_eq!(size_of::<UFrac64>(), 8);
+}
+
+#[test]
+fn odd_widths() {
+    assert_eq!(UFrac::<12>::MAX.to_fraction(), (12, 1));
+    assert_eq!(UFrac::<12>::MIN.to_fraction(), (1, 12));
+    assert_eq!(UFrac::<12>::ONE.to_fraction(), (1, 1));
+    assert_eq!(UFrac::<12>::ONE.to_bits(), 0b1000_0000_0000);
+    assert_eq!(UFrac::<24>::MAX.to_fraction(), (24, 1));
+    assert_eq!(UFrac::<24>::MIN.invert(), UFrac::<24>::MAX);
+    assert_eq!(UFrac::<3>::MAX.to_fraction(), (3, 1));
+    assert_eq!(format!("{:b}", UFrac::<12>::ONE), "100000000000");
+    assert_eq!(
+        format!("{:?}", UFrac::<12>::ONE),
+        "UFrac<12>(1/1 @ 0b1000_0000_0000)"
+    );
+    assert_eq!(
+        format!("{:?}", UFrac::<10>::MIN),
+        "UFrac<10>(1/10 @ 0b00_0000_0001)"
+    );
+
+    assert_eq!(UFrac::<12>::try_from(5u16).unwrap().to_fraction(), (5, 1));
+    assert_eq!(
+        UFrac::<12>::try_from(13u16),
+        Err(FractionError::OutOfRange { max: 12 })
+    );
+    assert_eq!(UFrac::<24>::try_from(0.5).unwrap().to_fraction(), (1, 2));
+    assert_eq!(
+        UFrac::<12>::try_from(1.618).unwrap().to_fraction(),
+        (144, 89)
+    );
+}
+
+#[test]
+fn odd_width_arithmetic() {
+    let third = UFrac::<12>::try_from(1.0 / 3.0).unwrap();
+    let half = UFrac::<12>::try_from(0.5).unwrap();
+    assert_eq!((third + half).to_fraction(), (5, 6));
+    assert_eq!((half - third).to_fraction(), (1, 6));
+    assert_eq!((third * half).to_fraction(), (1, 6));
+    assert_eq!((third / half).to_fraction(), (2, 3));
+    assert_eq!(UFrac::<12>::MAX.checked_add(UFrac::<12>::MIN), None);
+    assert_eq!(UFrac::<12>::MAX + UFrac::<12>::MIN, UFrac::<12>::MAX);
+    let fifth = UFrac::<5>::ONE / UFrac::<5>::try_from(5u8).unwrap();
+    let quarter = UFrac::<5>::ONE / UFrac::<5>::try_from(4u8).unwrap();
+    assert_eq!(fifth.exact_add(fifth).unwrap().to_fraction(), (2, 5));
+    // 9/20 is much too deep for a 5-bit fraction
+    assert_eq!(fifth.exact_add(quarter), None);
+}
+
+#[test]
+fn resize() {
+    for bits in 0..=u16::MAX {
+        let wide = UFrac16::from_bits(bits);
+        let narrow = wide.resize_lossy::<12>();
+        if wide.precision() <= 11 {
+            assert_eq!(wide.try_resize::<12>(), Some(narrow));
+            assert_eq!(narrow.resize_lossy::<16>(), wide);
+            let (num, denom) = wide.to_fraction();
+            assert_eq!(narrow.to_fraction(), (num, denom));
+        } else {
+            assert_eq!(wide.try_resize::<12>(), None);
+            // truncating keeps the first 11 steps, so it lands on an ancestor
+            let mut ancestor = wide;
+            while ancestor.precision() > 11 {
+                ancestor = ancestor.parent().unwrap();
+            }
+            assert_eq!(narrow.resize_lossy::<16>(), ancestor);
+        }
+    }
+    assert_eq!(UFrac64::from(UFrac8::PI).to_fraction(), (16, 5));
+    assert_eq!(UFrac8::try_from(UFrac32::ONE), Ok(UFrac8::ONE));
+    assert_eq!(
+        UFrac8::try_from(UFrac32::MIN),
+        Err(FractionError::PrecisionLoss { bits_needed: 32 })
+    );
+    assert_eq!(
+        UFrac8::try_from(UFrac32::try_from(9).unwrap()),
+        Err(FractionError::OutOfRange { max: 8 })
+    );
+    assert_eq!(
+        UFrac8::try_from("17/2".parse::<UFrac32>().unwrap()),
+        Err(FractionError::OutOfRange { max: 8 })
+    );
+    assert_eq!(UFrac32::MIN.to_ufrac8_lossy(), UFrac8::MIN);
+    assert_eq!(UFrac64::MAX.to_ufrac16_lossy(), UFrac16::MAX);
+}
+
+#[test]
+fn errors() {
+    fn parse(value: f64) -> Result<UFrac16, Box<dyn std::error::Error>> {
+        Ok(UFrac16::try_from(value)?)
+    }
+    assert_eq!(parse(0.5).unwrap(), UFrac16::try_from((1, 2)).unwrap());
+    assert_eq!(
+        parse(-0.5).unwrap_err().to_string(),
+        "an unsigned fraction can't be negative"
+    );
+    assert_eq!(
+        UFrac8::from_ratio(1, 9).unwrap_err().to_string(),
+        "the value needs 9 bits to be held exactly"
+    );
+}
@@ -0,0 +1,205 @@
+use crate::{FractionError, UFrac128, UFrac16, UFrac64, UFrac8};
+
+const SAMPLES: [UFrac128; 8] = [
+    UFrac128::MIN,
+    UFrac128::ONE,
+    UFrac128::GOLDEN_RATIO,
+    UFrac128::E,
+    UFrac128::PI,
+    UFrac128::MAX,
+    UFrac128::from_bits(0x5555_5555_5555_5555_5555_5555_5555_5555),
+    UFrac128::from_bits(0x8000_0000_0000_0000_0000_0000_0000_0001),
+];
+
+#[test]
+fn to_fraction() {
+    assert_eq!(UFrac128::ZERO.to_fraction(), (0, 1));
+    assert_eq!(UFrac128::ONE.to_fraction(), (1, 1));
+    assert_eq!(UFrac128::MIN.to_fraction(), (1, 128));
+    assert_eq!(UFrac128::MAX.to_fraction(), (128, 1));
+    assert_eq!(UFrac128::PI.to_fraction(), (355, 113));
+    assert_eq!(
+        UFrac128::E.to_fraction(),
+        (19_231_667_418_158, 7_074_935_062_587)
+    );
+    assert_eq!(
+        UFrac128::GOLDEN_RATIO.to_fraction(),
+        (
+            251_728_825_683_549_488_150_424_261,
+            155_576_970_220_531_065_681_649_693
+        )
+    );
+}
+
+#[test]
+fn tree() {
+    assert_eq!(UFrac128::MAX.precision(), 127);
+    assert!(UFrac128::MAX.is_leaf());
+    assert_eq!(UFrac128::MIN.invert(), UFrac128::MAX);
+    let (left, right) = UFrac128::ONE.children().unwrap();
+    assert_eq!(left.to_fraction(), (1, 2));
+    assert_eq!(right.to_fraction(), (2, 1));
+    assert_eq!(left.sibling(), Some(right));
+    assert_eq!(right.parent(), Some(UFrac128::ONE));
+    for frac in SAMPLES {
+        assert_eq!(frac.invert().invert(), frac);
+    }
+}
+
+#[test]
+fn from_f64() {
+    assert_eq!(UFrac128::try_from(0.5).unwrap().to_fraction(), (1, 2));
+    assert_eq!(UFrac128::try_from(100.0).unwrap().to_fraction(), (100, 1));
+    let pi = UFrac128::try_from(std::f64::consts::PI).unwrap();
+    // the nodes past 355/113 on the way towards 103993/33102 only get nearer the float than it after more than 127 steps
+    assert_eq!(pi, UFrac128::PI);
+    assert_eq!(
+        UFrac128::try_from(129u128),
+        Err(FractionError::OutOfRange { max: 128 })
+    );
+    assert_eq!(UFrac128::try_from(128u128), Ok(UFrac128::MAX));
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(UFrac128::from(UFrac8::PI).to_fraction(), (16, 5));
+    assert_eq!(UFrac128::from(UFrac16::MAX).to_fraction(), (16, 1));
+    let e = UFrac128::from(UFrac64::E);
+    let (num, denom) = UFrac64::E.to_fraction();
+    assert_eq!(e.to_fraction(), (num.into(), denom.into()));
+    assert_eq!(UFrac64::try_from(e), Ok(UFrac64::E));
+    assert_eq!(
+        UFrac64::try_from(UFrac128::E),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::from(UFrac128::E.precision()) + 1
+        })
+    );
+    assert_eq!(UFrac128::MIN.to_ufrac64_lossy(), UFrac64::MIN);
+}
+
+#[test]
+fn arithmetic() {
+    let three = UFrac128::try_from(3u128).unwrap();
+    assert_eq!((UFrac128::ONE + UFrac128::ONE).to_fraction(), (2, 1));
+    assert_eq!(three - UFrac128::ONE, UFrac128::try_from(2u128).unwrap());
+    assert_eq!(UFrac128::PI * UFrac128::PI.invert(), UFrac128::ONE);
+    assert_eq!(UFrac128::E / UFrac128::E, UFrac128::ONE);
+    assert_eq!(UFrac128::MAX.checked_add(UFrac128::MIN), None);
+    assert_eq!(UFrac128::MAX + UFrac128::MIN, UFrac128::MAX);
+    // the golden ratio's deep inputs would overflow a 128-bit engine
+    let golden = UFrac128::GOLDEN_RATIO;
+    let conjugate = golden.invert();
+    assert_eq!((golden - conjugate).to_fraction(), (1, 1));
+    for lhs in SAMPLES {
+        for rhs in SAMPLES {
+            let sum = lhs + rhs;
+            assert!(sum >= lhs.max(rhs));
+            if let Some(exact) = lhs.exact_add(rhs) {
+                assert_eq!(exact, sum);
+                assert_eq!(exact.exact_sub(rhs), Some(lhs));
+            }
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn to_float() {
+    let frac = UFrac128::from_bits(0xabaa_a8a2_aaa8_aae2_2a98_ba3a_aa8b_8a0f);
+    let (num, den) = frac.to_fraction();
+    assert_eq!(
+        (num, den),
+        (
+            1_495_203_923_934_734_686_512_357,
+            922_215_379_164_018_881_020_768
+        )
+    );
+    // rounding the numerator and denominator before dividing them lands one ulp too high
+    assert_eq!(frac.to_f64().to_bits(), 4_609_980_584_017_188_446);
+    assert_ne!(frac.to_f64().to_bits(), (num as f64 / den as f64).to_bits());
+    assert_eq!(frac.to_f32().to_bits(), 1_070_565_205);
+    assert_eq!(UFrac128::MIN.to_f64().to_bits(), (1.0f64 / 128.0).to_bits());
+    assert_eq!(UFrac128::MAX.to_f32().to_bits(), 128.0f32.to_bits());
+}
+
+#[test]
+fn continued_fraction() {
+    assert_eq!(
+        UFrac128::from_continued_fraction(&[1, u32::MAX]),
+        Ok(UFrac128::ONE)
+    );
+    assert_eq!(
+        UFrac128::from_continued_fraction(&[1, 64, u32::MAX, u32::MAX]),
+        UFrac128::from_continued_fraction(&[1, 64])
+    );
+    // the golden ratio, far deeper than 128 bits go
+    let golden = UFrac128::from_continued_fraction(&[1; 1000]).unwrap();
+    assert_eq!(golden.precision(), 127);
+    assert!(golden
+        .to_continued_fraction()
+        .all(|term| term == 1 || term == 2));
+    assert_eq!(
+        UFrac128::from_continued_fraction(&golden.to_continued_fraction().collect::<Vec<_>>()),
+        Ok(golden)
+    );
+}
+
+#[test]
+fn from_continued_fraction() {
+    // deep continued fractions round the same way as their exact ratios, including ones whose convergents outgrow what the rounding needs
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = |modulus: u64| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        u32::try_from(seed % modulus).unwrap()
+    };
+    for round in 0..2000 {
+        let first = next(3);
+        let mut terms = vec![first];
+        let (mut num, mut den, mut prev_num, mut prev_den) =
+            (u128::from(first), 1u128, 1u128, 0u128);
+        loop {
+            let term = next(if round % 4 == 0 { 1 << 20 } else { 40 }) + 1;
+            let convergent =
+                |num: u128, prev: u128| u128::from(term).checked_mul(num)?.checked_add(prev);
+            let (Some(next_num), Some(next_den)) =
+                (convergent(num, prev_num), convergent(den, prev_den))
+            else {
+                break;
+            };
+            (prev_num, prev_den, num, den) = (num, den, next_num, next_den);
+            terms.push(term);
+        }
+        assert_eq!(
+            UFrac128::from_continued_fraction(&terms),
+            UFrac128::nearest_ratio(num, den),
+            "{terms:?}"
+        );
+    }
+}
+
+#[test]
+fn simplest_between() {
+    // ranges around `ONE` part ways at the top bit
+    assert_eq!(
+        UFrac128::simplest_between(UFrac128::MIN, UFrac128::MAX),
+        Some(UFrac128::ONE)
+    );
+    assert_eq!(
+        UFrac128::simplest_between_inclusive(UFrac128::from_bits(1), UFrac128::MAX),
+        UFrac128::ONE
+    );
+    assert_eq!(
+        UFrac128::simplest_between(UFrac128::ONE, UFrac128::MAX).map(UFrac128::to_fraction),
+        Some((2, 1))
+    );
+    assert_eq!(
+        UFrac128::simplest_between_inclusive(UFrac128::MIN, UFrac128::ONE),
+        UFrac128::ONE
+    );
+    assert_eq!(
+        UFrac128::simplest_between(UFrac128::E, UFrac128::PI).map(UFrac128::to_fraction),
+        Some((3, 1))
+    );
+}
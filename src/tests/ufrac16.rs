@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use crate::UFrac16;
+use crate::{FractionError, UFrac16};
 
 #[test]
 fn to_fraction() {
@@ -63,11 +63,11 @@ fn ordering() {
             .cmp(&UFrac16::try_from(3).unwrap()),
         Ordering::Greater
     );
-    println!("{:?} = {0}", UFrac16::try_from(1.20001).unwrap());
+    println!("{:?} = {0}", UFrac16::try_from(1.21).unwrap());
     assert_eq!(
         UFrac16::try_from(1.2)
             .unwrap()
-            .cmp(&UFrac16::try_from(1.20001).unwrap()),
+            .cmp(&UFrac16::try_from(1.21).unwrap()),
         Ordering::Less
     );
     println!("{:?} = {0}", UFrac16::try_from(2).unwrap());
@@ -107,3 +107,68 @@ fn is_leaf() {
     assert!(UFrac16::PI.is_leaf());
     assert!(UFrac16::MAX.is_leaf());
 }
+
+#[test]
+fn add_sub() {
+    let half = UFrac16::from_bits(0x4000);
+    assert_eq!(half + half, UFrac16::ONE);
+    assert_eq!((UFrac16::E - UFrac16::ONE).to_fraction(), (500, 291));
+    assert_eq!(UFrac16::MAX + UFrac16::ONE, UFrac16::MAX);
+    assert_eq!(half - UFrac16::ONE, UFrac16::ZERO);
+}
+
+#[test]
+fn mul_div() {
+    let half = UFrac16::from_bits(0x4000);
+    let two = UFrac16::try_from(2).unwrap();
+    assert_eq!(half * two, UFrac16::ONE);
+    assert_eq!(UFrac16::ONE / two, half);
+    assert_eq!(UFrac16::PI / UFrac16::PI, UFrac16::ONE);
+    assert_eq!(UFrac16::MAX * two, UFrac16::MAX);
+    assert_eq!(UFrac16::MIN * UFrac16::MIN, UFrac16::ZERO);
+}
+
+#[test]
+fn to_f64_is_injective() {
+    let mut floats: Vec<f64> = (0..=u16::MAX)
+        .map(UFrac16::from_bits)
+        .map(f64::from)
+        .collect();
+    let len = floats.len();
+    floats.dedup();
+    assert_eq!(floats.len(), len);
+}
+
+#[test]
+fn from_f16_bits() {
+    assert_eq!(UFrac16::from_f16_bits(0x3c00), Ok(UFrac16::ONE));
+    assert_eq!(
+        UFrac16::from_f16_bits(0x3800).unwrap().to_fraction(),
+        (1, 2)
+    );
+    assert_eq!(
+        UFrac16::from_f16_bits(0x4200).unwrap().to_fraction(),
+        (3, 1)
+    );
+    // the nearest half-precision values to `1/10` and `1/3`
+    assert_eq!(
+        UFrac16::from_f16_bits(0x2e66).unwrap().to_fraction(),
+        (1, 10)
+    );
+    assert_eq!(
+        UFrac16::from_f16_bits(0x3555).unwrap().to_fraction(),
+        (1, 3)
+    );
+    assert_eq!(
+        UFrac16::from_f16_bits(0x4c00).unwrap().to_fraction(),
+        (16, 1)
+    );
+    // `15.8984375`, the nearest half-precision value to `15.9`, just below `MAX`
+    assert_eq!(UFrac16::from_f16_bits(0x4bf3), Ok(UFrac16::MAX));
+    assert_eq!(UFrac16::try_from(15.9f32), Ok(UFrac16::MAX));
+    assert_eq!(UFrac16::from_f16_bits(0x0000), Ok(UFrac16::ZERO));
+    assert_eq!(UFrac16::from_f16_bits(0x8000), Ok(UFrac16::ZERO));
+    assert_eq!(UFrac16::from_f16_bits(0xbc00), Err(FractionError::Negative));
+    assert_eq!(UFrac16::from_f16_bits(0x7c00), Err(FractionError::Infinite));
+    assert_eq!(UFrac16::from_f16_bits(0x7e00), Err(FractionError::NaN));
+}
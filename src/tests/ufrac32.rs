@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use crate::UFrac32;
+use crate::{FractionError, UFrac32};
 
 #[test]
 fn to_fraction() {
@@ -66,11 +66,11 @@ fn ordering() {
             .cmp(&UFrac32::try_from(3).unwrap()),
         Ordering::Greater
     );
-    println!("{:?} = {0}", UFrac32::try_from(1.20001).unwrap());
+    println!("{:?} = {0}", UFrac32::try_from(1.21).unwrap());
     assert_eq!(
         UFrac32::try_from(1.2)
             .unwrap()
-            .cmp(&UFrac32::try_from(1.20001).unwrap()),
+            .cmp(&UFrac32::try_from(1.21).unwrap()),
         Ordering::Less
     );
     println!("{:?} = {0}", UFrac32::try_from(2).unwrap());
@@ -104,4 +104,119 @@ fn is_leaf() {
     assert!(!UFrac32::E.is_leaf());
     assert!(UFrac32::PI.is_leaf());
     assert!(UFrac32::MAX.is_leaf());
-}
\ No newline at end of file
+}
+
+#[test]
+fn add_sub() {
+    let half = UFrac32::from_bits(0x4000_0000);
+    assert_eq!(half + half, UFrac32::ONE);
+    assert_eq!((UFrac32::PI - UFrac32::ONE).to_fraction(), (2632, 1229));
+    assert_eq!(UFrac32::MAX + UFrac32::ONE, UFrac32::MAX);
+    assert_eq!(half - UFrac32::ONE, UFrac32::ZERO);
+}
+
+#[test]
+fn mul_div() {
+    let half = UFrac32::from_bits(0x4000_0000);
+    let two = UFrac32::try_from(2).unwrap();
+    assert_eq!(half * two, UFrac32::ONE);
+    assert_eq!(UFrac32::ONE / two, half);
+    assert_eq!(UFrac32::PI / UFrac32::PI, UFrac32::ONE);
+    assert_eq!(UFrac32::MAX * two, UFrac32::MAX);
+    assert_eq!(UFrac32::MIN * UFrac32::MIN, UFrac32::ZERO);
+}
+
+#[test]
+fn checked_exact() {
+    let third = UFrac32::from_bits(0x2000_0000);
+    assert_eq!(UFrac32::MAX.checked_add(UFrac32::MIN), None);
+    assert_eq!(UFrac32::MAX.checked_div(UFrac32::ONE), Some(UFrac32::MAX));
+    assert_eq!(third.exact_mul(third).unwrap().to_fraction(), (1, 9));
+    assert_eq!(UFrac32::PI.exact_mul(UFrac32::PI), None);
+    assert_eq!(UFrac32::PI.saturating_mul(UFrac32::MAX), UFrac32::MAX);
+}
+
+#[test]
+fn from_ratio() {
+    assert_eq!(UFrac32::try_from((3, 7)).unwrap().to_fraction(), (3, 7));
+    assert_eq!(UFrac32::try_from((300, 700)).unwrap().to_fraction(), (3, 7));
+    assert_eq!(UFrac32::try_from((3, 0)), Err(FractionError::Infinite));
+    assert_eq!(
+        UFrac32::from_ratio(1, 33),
+        Err(FractionError::PrecisionLoss { bits_needed: 33 })
+    );
+    assert_eq!(
+        UFrac32::nearest_ratio(1_000_000, 1_000_001),
+        Ok(UFrac32::ONE)
+    );
+    assert_eq!(UFrac32::nearest_ratio(65, 2), Ok(UFrac32::MAX));
+}
+
+#[test]
+fn from_f32() {
+    assert_eq!(UFrac32::try_from(0.5f32).unwrap().to_fraction(), (1, 2));
+    assert_eq!(UFrac32::try_from(0.1f32).unwrap().to_fraction(), (1, 10));
+    // this float is only `5973/1666` to single precision, which is where the descent should stop, though the exact value of it is nearer another node
+    assert_eq!(
+        UFrac32::try_from(3.585_234_2_f32).unwrap().to_fraction(),
+        (5973, 1666)
+    );
+    assert_eq!(
+        UFrac32::try_from(f64::from(3.585_234_2_f32))
+            .unwrap()
+            .to_fraction(),
+        (360_567, 100_570)
+    );
+    assert_eq!(UFrac32::try_from(-0.0f32), Ok(UFrac32::ZERO));
+    assert_eq!(UFrac32::try_from(-1.0f32), Err(FractionError::Negative));
+    assert_eq!(
+        UFrac32::try_from(f32::INFINITY),
+        Err(FractionError::Infinite)
+    );
+    assert_eq!(UFrac32::try_from(f32::NAN), Err(FractionError::NaN));
+}
+
+#[test]
+fn from_f64_rounded() {
+    use crate::RoundingMode::{Ceil, Floor, Simplest};
+    use std::f64::consts::PI;
+
+    let simplest = |tolerance| UFrac32::from_f64_rounded(PI, Simplest { tolerance });
+    assert_eq!(simplest(0.2).unwrap().to_fraction(), (3, 1));
+    assert_eq!(simplest(0.01).unwrap().to_fraction(), (22, 7));
+    assert_eq!(simplest(0.001).unwrap().to_fraction(), (201, 64));
+    assert_eq!(simplest(5.0), Ok(UFrac32::ZERO));
+    // with no node close enough, the nearest one
+    assert_eq!(
+        simplest(0.0),
+        UFrac32::from_f64_rounded(PI, crate::RoundingMode::Nearest)
+    );
+    let floor = UFrac32::from_f64_rounded(PI, Floor).unwrap();
+    let ceil = UFrac32::from_f64_rounded(PI, Ceil).unwrap();
+    assert!(floor.to_f64() < PI && PI < ceil.to_f64());
+    assert_eq!(UFrac32::from_f64_rounded(33.0, Floor), Ok(UFrac32::MAX));
+    assert_eq!(
+        UFrac32::from_f64_rounded(33.0, Ceil),
+        Err(FractionError::OutOfRange { max: 32 })
+    );
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("0.1".parse::<UFrac32>().unwrap().to_fraction(), (1, 10));
+    assert_eq!(
+        "3.14159".parse::<UFrac32>(),
+        Err(FractionError::PrecisionLoss { bits_needed: 63 })
+    );
+    assert_eq!("-0.0".parse(), Ok(UFrac32::ZERO));
+}
+
+#[test]
+fn display() {
+    let frac = |num, den| UFrac32::from_ratio(num, den).unwrap();
+    // rounding up carries through the nines
+    assert_eq!(format!("{:.1}", frac(49, 25)), "2.0");
+    assert_eq!(format!("{:.1}", frac(24, 25)), "1.0");
+    assert_eq!(format!("{:#}", frac(49, 25)), "1 24/25");
+    assert_eq!(frac(1, 28).to_decimal_string(20), "0.03(571428)");
+}
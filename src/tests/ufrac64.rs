@@ -0,0 +1,131 @@
+use crate::{farey::Descent, wide::U256, FractionError, UFrac64};
+
+const SAMPLES: [UFrac64; 9] = [
+    UFrac64::ZERO,
+    UFrac64::MIN,
+    UFrac64::ONE,
+    UFrac64::GOLDEN_RATIO,
+    UFrac64::E,
+    UFrac64::PI,
+    UFrac64::MAX,
+    UFrac64::from_bits(0x5555_5555_5555_5555),
+    UFrac64::from_bits(0x0123_4567_89AB_CDEF),
+];
+
+#[test]
+fn to_fraction() {
+    assert_eq!(UFrac64::ONE.to_fraction(), (1, 1));
+    assert_eq!(
+        UFrac64::from_bits(0x4000_0000_0000_0000).to_fraction(),
+        (1, 2)
+    );
+    assert_eq!(
+        UFrac64::from_bits(0xC000_0000_0000_0000).to_fraction(),
+        (2, 1)
+    );
+    assert_eq!(
+        UFrac64::from_bits(0x4800_0000_0000_0000).to_fraction(),
+        (4, 7)
+    );
+    assert_eq!(UFrac64::MAX.to_fraction(), (64, 1));
+    assert_eq!(UFrac64::MIN.to_fraction(), (1, 64));
+}
+
+#[test]
+fn add_sub() {
+    let half = UFrac64::from_bits(0x4000_0000_0000_0000);
+    assert_eq!(half + half, UFrac64::ONE);
+    assert_eq!(
+        (UFrac64::E - UFrac64::ONE).to_fraction(),
+        (214_941_996, 125_091_235)
+    );
+    assert_eq!(UFrac64::MAX + UFrac64::ONE, UFrac64::MAX);
+    assert_eq!(half - UFrac64::ONE, UFrac64::ZERO);
+}
+
+#[test]
+fn mul_div() {
+    let half = UFrac64::from_bits(0x4000_0000_0000_0000);
+    let two = UFrac64::try_from(2).unwrap();
+    assert_eq!(half * two, UFrac64::ONE);
+    assert_eq!(UFrac64::ONE / two, half);
+    assert_eq!(UFrac64::PI / UFrac64::PI, UFrac64::ONE);
+    assert_eq!(UFrac64::MAX * two, UFrac64::MAX);
+    assert_eq!(UFrac64::MIN * UFrac64::MIN, UFrac64::ZERO);
+}
+
+#[test]
+fn arithmetic_matches_exact_rounding() {
+    for lhs in SAMPLES {
+        for rhs in SAMPLES {
+            let (lhs_num, lhs_denom) = lhs.to_fraction();
+            let (rhs_num, rhs_denom) = rhs.to_fraction();
+            let (lhs_num, lhs_denom) = (u128::from(lhs_num), u128::from(lhs_denom));
+            let (rhs_num, rhs_denom) = (u128::from(rhs_num), u128::from(rhs_denom));
+            let nearest = |num, denom| {
+                UFrac64::from_bits(
+                    u64::try_from(
+                        Descent::new(63).nearest(U256::from_u128(num), U256::from_u128(denom)),
+                    )
+                    .unwrap(),
+                )
+            };
+            assert_eq!(
+                lhs + rhs,
+                nearest(
+                    lhs_num * rhs_denom + rhs_num * lhs_denom,
+                    lhs_denom * rhs_denom
+                )
+            );
+            assert_eq!(lhs * rhs, nearest(lhs_num * rhs_num, lhs_denom * rhs_denom));
+            if lhs >= rhs {
+                assert_eq!(
+                    lhs - rhs,
+                    nearest(
+                        lhs_num * rhs_denom - rhs_num * lhs_denom,
+                        lhs_denom * rhs_denom
+                    )
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn exact() {
+    let three = UFrac64::try_from(3).unwrap();
+    assert_eq!(three.exact_add(UFrac64::ONE), UFrac64::try_from(4).ok());
+    assert_eq!(UFrac64::E.exact_div(UFrac64::E), Some(UFrac64::ONE));
+    assert_eq!(
+        UFrac64::PI.exact_mul(UFrac64::PI.invert()),
+        Some(UFrac64::ONE)
+    );
+    assert_eq!(UFrac64::MAX.exact_sub(UFrac64::MAX), Some(UFrac64::ZERO));
+    // `PI` is a leaf, so `PI + 1` is one step too deep
+    assert_eq!(UFrac64::PI.exact_add(UFrac64::ONE), None);
+    assert!(UFrac64::PI.checked_add(UFrac64::ONE).is_some());
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn from_ratio() {
+    // consecutive Fibonacci numbers, 62 steps down the tree; `f64` can't tell this apart from its neighbours
+    let (num, den) = (10_610_209_857_723, 6_557_470_319_842);
+    assert_eq!(
+        UFrac64::from_ratio(num, den).unwrap().to_fraction(),
+        (num, den)
+    );
+    assert_ne!(
+        UFrac64::try_from(num as f64 / den as f64)
+            .unwrap()
+            .to_fraction(),
+        (num, den)
+    );
+    assert_eq!(UFrac64::from_ratio(u64::MAX, u64::MAX), Ok(UFrac64::ONE));
+    assert_eq!(
+        UFrac64::from_ratio(u64::MAX - 1, u64::MAX),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::from(u64::MAX)
+        })
+    );
+}
@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use crate::UFrac8;
+use crate::{Direction, FractionError, UFrac32, UFrac8};
 
 #[test]
 fn to_fraction() {
@@ -55,7 +55,21 @@ fn from_f64() {
     assert_eq!(UFrac8::try_from(1.0).unwrap().to_fraction(), (1, 1));
     assert_eq!(UFrac8::try_from(0.5).unwrap().to_fraction(), (1, 2));
     println!("{}", UFrac8::try_from(1.618).unwrap());
-    assert_eq!(UFrac8::try_from(1.618).unwrap().to_fraction(), (21, 13));
+    assert_eq!(UFrac8::try_from(1.618).unwrap().to_fraction(), (34, 21));
+    // just below `MAX`, and between two nodes on the right edge of the tree
+    assert_eq!(UFrac8::try_from(7.9), Ok(UFrac8::MAX));
+    assert_eq!(UFrac8::try_from(6.6).unwrap().to_fraction(), (13, 2));
+    assert_eq!(UFrac8::try_from(6.9).unwrap().to_fraction(), (7, 1));
+    assert_eq!(UFrac8::try_from(0.01), Ok(UFrac8::ZERO));
+}
+
+#[test]
+fn from_f32() {
+    assert_eq!(UFrac8::try_from(7.9f32), Ok(UFrac8::MAX));
+    assert_eq!(UFrac8::try_from(6.6f32).unwrap().to_fraction(), (13, 2));
+    assert_eq!(UFrac8::try_from(0.13f32), Ok(UFrac8::MIN));
+    // `7.8984375`, the nearest half-precision value to `7.9`
+    assert_eq!(UFrac8::from_f16_bits(0x47e6), Ok(UFrac8::MAX));
 }
 
 #[test]
@@ -112,3 +126,690 @@ fn is_leaf() {
     assert!(UFrac8::PI.is_leaf());
     assert!(UFrac8::MAX.is_leaf());
 }
+
+#[test]
+fn add_sub() {
+    let half = UFrac8::from_bits(0b0100_0000);
+    let third = UFrac8::from_bits(0b0010_0000);
+    assert_eq!(half + half, UFrac8::ONE);
+    assert_eq!((third + third).to_fraction(), (2, 3));
+    assert_eq!((UFrac8::ONE - third).to_fraction(), (2, 3));
+    assert_eq!((UFrac8::E - UFrac8::ONE).to_fraction(), (12, 7));
+    let mut frac = UFrac8::ONE;
+    frac += half;
+    assert_eq!(frac.to_fraction(), (3, 2));
+    frac -= UFrac8::ONE;
+    assert_eq!(frac, half);
+}
+
+#[test]
+fn add_sub_saturate() {
+    assert_eq!(UFrac8::MAX + UFrac8::ONE, UFrac8::MAX);
+    assert_eq!(UFrac8::ONE - UFrac8::MAX, UFrac8::ZERO);
+    assert_eq!(UFrac8::MIN - UFrac8::MIN, UFrac8::ZERO);
+    assert_eq!(UFrac8::ZERO + UFrac8::MIN, UFrac8::MIN);
+}
+
+/// Check `op` against every pair of `UFrac8`s, making sure no representable value is closer to `exact` than the result.
+fn assert_rounds_to_nearest(
+    op: impl Fn(UFrac8, UFrac8) -> UFrac8,
+    exact: impl Fn((u64, u64), (u64, u64)) -> (u64, u64),
+) {
+    let values: Vec<(u64, u64)> = (0..=255)
+        .map(|bits| {
+            let (num, denom) = UFrac8::from_bits(bits).to_fraction();
+            (u64::from(num), u64::from(denom))
+        })
+        .collect();
+    // `|a/b - x/y| * b * y`, where `y` is shared by every candidate
+    let scaled_distance = |(num, denom): (u64, u64), (x_num, x_denom): (u64, u64)| {
+        (num * x_denom).abs_diff(x_num * denom)
+    };
+    for lhs in 0..=255 {
+        for rhs in 0..=255 {
+            let exact = exact(values[usize::from(lhs)], values[usize::from(rhs)]);
+            let (num, denom) = op(UFrac8::from_bits(lhs), UFrac8::from_bits(rhs)).to_fraction();
+            let result = (u64::from(num), u64::from(denom));
+            assert!(values.iter().all(|&value| {
+                scaled_distance(result, exact) * value.1 <= scaled_distance(value, exact) * result.1
+            }));
+        }
+    }
+}
+
+#[test]
+fn add_rounds_to_nearest() {
+    assert_rounds_to_nearest(
+        |lhs, rhs| lhs + rhs,
+        |(lhs_num, lhs_denom), (rhs_num, rhs_denom)| {
+            (
+                lhs_num * rhs_denom + rhs_num * lhs_denom,
+                lhs_denom * rhs_denom,
+            )
+        },
+    );
+}
+
+#[test]
+fn mul_div() {
+    let half = UFrac8::from_bits(0b0100_0000);
+    let two = UFrac8::try_from(2).unwrap();
+    assert_eq!(half * two, UFrac8::ONE);
+    assert_eq!(UFrac8::ONE / two, half);
+    assert_eq!((UFrac8::E * half).to_fraction(), (19, 14));
+    assert_eq!(UFrac8::MAX * two, UFrac8::MAX);
+    assert_eq!(UFrac8::MIN * UFrac8::MIN, UFrac8::ZERO);
+    assert_eq!(UFrac8::ZERO / UFrac8::PI, UFrac8::ZERO);
+    let mut frac = UFrac8::PI;
+    frac /= UFrac8::PI;
+    assert_eq!(frac, UFrac8::ONE);
+    frac *= UFrac8::GOLDEN_RATIO;
+    assert_eq!(frac, UFrac8::GOLDEN_RATIO);
+}
+
+#[test]
+#[should_panic = "Can't invert `0/1`"]
+fn div_by_zero() {
+    let _ = UFrac8::ONE / UFrac8::ZERO;
+}
+
+#[test]
+fn mul_rounds_to_nearest() {
+    assert_rounds_to_nearest(
+        |lhs, rhs| lhs * rhs,
+        |(lhs_num, lhs_denom), (rhs_num, rhs_denom)| (lhs_num * rhs_num, lhs_denom * rhs_denom),
+    );
+}
+
+#[test]
+fn checked() {
+    let half = UFrac8::from_bits(0b0100_0000);
+    let two = UFrac8::try_from(2).unwrap();
+    assert_eq!(UFrac8::ONE.checked_add(UFrac8::ONE), Some(two));
+    assert_eq!(UFrac8::MAX.checked_add(UFrac8::ZERO), Some(UFrac8::MAX));
+    assert_eq!(UFrac8::MAX.checked_add(UFrac8::MIN), None);
+    assert_eq!(UFrac8::ONE.checked_sub(half), Some(half));
+    assert_eq!(half.checked_sub(UFrac8::ONE), None);
+    assert_eq!(
+        UFrac8::MAX.checked_mul(half),
+        Some(UFrac8::try_from(4).unwrap())
+    );
+    assert_eq!(UFrac8::MAX.checked_mul(two), None);
+    assert_eq!(UFrac8::ONE.checked_div(two), Some(half));
+    assert_eq!(UFrac8::ONE.checked_div(UFrac8::ZERO), None);
+    assert_eq!(UFrac8::MIN.checked_mul(UFrac8::MIN), Some(UFrac8::ZERO));
+}
+
+#[test]
+fn saturating() {
+    assert_eq!(UFrac8::MAX.saturating_add(UFrac8::ONE), UFrac8::MAX);
+    assert_eq!(UFrac8::ZERO.saturating_sub(UFrac8::ONE), UFrac8::ZERO);
+    assert_eq!(UFrac8::MAX.saturating_mul(UFrac8::MAX), UFrac8::MAX);
+    assert_eq!(UFrac8::MAX.saturating_div(UFrac8::MIN), UFrac8::MAX);
+}
+
+#[test]
+fn exact() {
+    let half = UFrac8::from_bits(0b0100_0000);
+    let third = UFrac8::from_bits(0b0010_0000);
+    assert_eq!(third.exact_add(third).unwrap().to_fraction(), (2, 3));
+    assert_eq!(UFrac8::E.exact_add(UFrac8::E), None);
+    assert_eq!(UFrac8::ONE.exact_sub(third).unwrap().to_fraction(), (2, 3));
+    assert_eq!(UFrac8::MIN.exact_sub(UFrac8::ONE), None);
+    assert_eq!(half.exact_mul(half).unwrap().to_fraction(), (1, 4));
+    assert_eq!(UFrac8::MIN.exact_mul(UFrac8::MIN), None);
+    assert_eq!(UFrac8::PI.exact_div(UFrac8::PI), Some(UFrac8::ONE));
+    assert_eq!(UFrac8::ONE.exact_div(UFrac8::ZERO), None);
+    assert_eq!(UFrac8::ZERO.exact_mul(UFrac8::PI), Some(UFrac8::ZERO));
+}
+
+#[test]
+fn sub_rounds_to_nearest() {
+    assert_rounds_to_nearest(
+        |lhs, rhs| lhs - rhs,
+        |(lhs_num, lhs_denom), (rhs_num, rhs_denom)| {
+            (
+                (lhs_num * rhs_denom).saturating_sub(rhs_num * lhs_denom),
+                lhs_denom * rhs_denom,
+            )
+        },
+    );
+}
+
+#[test]
+fn from_ratio() {
+    assert_eq!(UFrac8::from_ratio(0, 5), Ok(UFrac8::ZERO));
+    assert_eq!(UFrac8::from_ratio(3, 7).unwrap().to_fraction(), (3, 7));
+    assert_eq!(UFrac8::from_ratio(6, 14).unwrap().to_fraction(), (3, 7));
+    assert_eq!(UFrac8::try_from((8, 1)), Ok(UFrac8::MAX));
+    assert_eq!(UFrac8::from_ratio(13, 34).unwrap().to_fraction(), (13, 34));
+    assert_eq!(UFrac8::from_ratio(1, 0), Err(FractionError::Infinite));
+    assert_eq!(
+        UFrac8::from_ratio(9, 1),
+        Err(FractionError::OutOfRange { max: 8 })
+    );
+    assert_eq!(
+        UFrac8::try_from((17, 2)),
+        Err(FractionError::OutOfRange { max: 8 })
+    );
+    assert_eq!(
+        UFrac8::try_from((21, 55)),
+        Err(FractionError::PrecisionLoss { bits_needed: 9 })
+    );
+}
+
+#[test]
+fn nearest_ratio() {
+    assert_eq!(UFrac8::nearest_ratio(3, 7).unwrap().to_fraction(), (3, 7));
+    assert_eq!(UFrac8::nearest_ratio(1, 9), Ok(UFrac8::MIN));
+    assert_eq!(UFrac8::nearest_ratio(1, 17), Ok(UFrac8::ZERO));
+    assert_eq!(UFrac8::nearest_ratio(200, 1), Ok(UFrac8::MAX));
+    assert_eq!(UFrac8::nearest_ratio(1, 0), Err(FractionError::Infinite));
+    // every node is its own nearest ratio
+    for bits in 0..=u8::MAX {
+        let frac = UFrac8::from_bits(bits);
+        let (num, den) = frac.to_fraction();
+        assert_eq!(UFrac8::nearest_ratio(num, den), Ok(frac));
+        assert_eq!(UFrac8::from_ratio(num, den), Ok(frac));
+    }
+}
+
+#[test]
+fn to_float() {
+    assert_eq!(UFrac8::ZERO.to_f64().to_bits(), 0.0f64.to_bits());
+    assert_eq!(UFrac8::MAX.to_f32().to_bits(), 8.0f32.to_bits());
+    assert_eq!(f64::from(UFrac8::PI).to_bits(), (16.0f64 / 5.0).to_bits());
+    // the numerator and denominator are exact in either float, so dividing them directly rounds correctly too
+    for bits in 0..=u8::MAX {
+        let frac = UFrac8::from_bits(bits);
+        let (num, den) = frac.to_fraction();
+        assert_eq!(
+            frac.to_f64().to_bits(),
+            (f64::from(num) / f64::from(den)).to_bits()
+        );
+        assert_eq!(
+            frac.to_f32().to_bits(),
+            (f32::from(num) / f32::from(den)).to_bits()
+        );
+    }
+}
+
+#[test]
+fn from_f64_rounded() {
+    use crate::RoundingMode::{Ceil, Floor, Nearest, TowardZero};
+
+    let mut nodes: Vec<UFrac8> = (0..=u8::MAX).map(UFrac8::from_bits).collect();
+    nodes.sort();
+    // `k/1024` is exact as an `f64`, so each node can be compared with it exactly
+    let distance = |frac: UFrac8, k: i32| {
+        let (num, den) = frac.to_fraction();
+        (
+            i64::from(num) * 1024 - i64::from(k) * i64::from(den),
+            i64::from(den),
+        )
+    };
+    for k in 1..=9 * 1024 {
+        let value = f64::from(k) / 1024.0;
+        let floor = UFrac8::from_f64_rounded(value, Floor).unwrap();
+        let nearest = UFrac8::from_f64_rounded(value, Nearest).unwrap();
+        let above: Vec<UFrac8> = nodes
+            .iter()
+            .copied()
+            .filter(|&frac| distance(frac, k).0 >= 0)
+            .collect();
+        let below: Vec<UFrac8> = nodes
+            .iter()
+            .copied()
+            .filter(|&frac| distance(frac, k).0 <= 0)
+            .collect();
+        assert_eq!(floor, *below.last().unwrap());
+        assert_eq!(UFrac8::from_f64_rounded(value, TowardZero), Ok(floor));
+        assert_eq!(
+            UFrac8::from_f64_rounded(value, Ceil),
+            above
+                .first()
+                .copied()
+                .ok_or(FractionError::OutOfRange { max: 8 })
+        );
+        // no node is closer than the nearest one, and any that is as close is deeper
+        let (nearest_num, nearest_den) = distance(nearest, k);
+        for frac in nodes.iter().copied() {
+            let (num, den) = distance(frac, k);
+            let ordering = (num.abs() * nearest_den).cmp(&(nearest_num.abs() * den));
+            assert!(
+                ordering.is_gt() || (ordering.is_eq() && frac.precision() >= nearest.precision())
+            );
+        }
+    }
+    assert_eq!(UFrac8::from_f64_rounded(1e300, Nearest), Ok(UFrac8::MAX));
+    assert_eq!(UFrac8::from_f64_rounded(1e-300, Floor), Ok(UFrac8::ZERO));
+    assert_eq!(UFrac8::from_f64_rounded(1e-300, Ceil), Ok(UFrac8::MIN));
+    assert_eq!(UFrac8::from_f64_rounded(-0.0, Ceil), Ok(UFrac8::ZERO));
+    assert_eq!(
+        UFrac8::from_f64_rounded(-1.0, Floor),
+        Err(FractionError::Negative)
+    );
+    assert_eq!(
+        UFrac8::from_f64_rounded(f64::NAN, Nearest),
+        Err(FractionError::NaN)
+    );
+}
+
+#[test]
+fn from_str() {
+    let parse = |text: &str| text.parse::<UFrac8>().map(UFrac8::to_fraction);
+    assert_eq!(parse("3/7"), Ok((3, 7)));
+    assert_eq!(parse("6/14"), Ok((3, 7)));
+    assert_eq!(parse("+3/7"), Ok((3, 7)));
+    assert_eq!(parse("1 1/2"), Ok((3, 2)));
+    assert_eq!(parse("2  3/4"), Ok((11, 4)));
+    assert_eq!(parse("0.375"), Ok((3, 8)));
+    assert_eq!(parse("2.50"), Ok((5, 2)));
+    assert_eq!(parse(".5"), Ok((1, 2)));
+    assert_eq!(parse("5."), Ok((5, 1)));
+    assert_eq!(parse(".0"), Ok((0, 1)));
+    // more zeros than a 256-bit denominator could hold
+    assert_eq!(parse(&format!("0.5{}", "0".repeat(80))), Ok((1, 2)));
+    assert_eq!(parse(&format!("3.{}", "0".repeat(80))), Ok((3, 1)));
+    assert_eq!(parse("8"), Ok((8, 1)));
+    assert_eq!(parse("0"), Ok((0, 1)));
+    assert_eq!(parse("-0"), Ok((0, 1)));
+    assert_eq!(parse("-3/7"), Err(FractionError::Negative));
+    assert_eq!(parse("3/0"), Err(FractionError::Infinite));
+    assert_eq!(parse("9"), Err(FractionError::OutOfRange { max: 8 }));
+    assert_eq!(parse("8.01"), Err(FractionError::OutOfRange { max: 8 }));
+    assert_eq!(
+        parse("0.1"),
+        Err(FractionError::PrecisionLoss { bits_needed: 10 })
+    );
+    // too long for 256 bits: a whole part that long is out of range, and a denominator or fraction that long too deep
+    let huge = format!("1{}", "0".repeat(80));
+    assert_eq!(parse(&huge), Err(FractionError::OutOfRange { max: 8 }));
+    assert_eq!(
+        parse(&format!("{huge}.5")),
+        Err(FractionError::OutOfRange { max: 8 })
+    );
+    assert_eq!(
+        parse(&format!("{huge}/3")),
+        Err(FractionError::OutOfRange { max: 8 })
+    );
+    assert_eq!(parse(&format!("-{huge}")), Err(FractionError::Negative));
+    assert_eq!(
+        parse(&format!("1/{huge}")),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::MAX
+        })
+    );
+    assert_eq!(
+        parse(&format!("0.{}1", "0".repeat(80))),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::MAX
+        })
+    );
+    assert_eq!(
+        parse(&format!("2.{}1", "0".repeat(80))),
+        Err(FractionError::PrecisionLoss {
+            bits_needed: u128::MAX
+        })
+    );
+    assert_eq!(
+        parse(&format!("9.{}1", "0".repeat(80))),
+        Err(FractionError::OutOfRange { max: 8 })
+    );
+    for text in [
+        "", "-", ".", "3/", "/7", "3 /7", "1 1", "1.5/2", " 3/7", "3/7 ", "0x10", "--1",
+    ] {
+        assert_eq!(parse(text), Err(FractionError::InvalidSyntax), "{text:?}");
+    }
+    // every value reads back from how it displays
+    for bits in 0..=u8::MAX {
+        let frac = UFrac8::from_bits(bits);
+        assert_eq!(frac.to_string().parse(), Ok(frac));
+    }
+}
+
+#[test]
+fn display() {
+    let frac = |num, den| UFrac8::from_ratio(num, den).unwrap();
+    assert_eq!(frac(19, 7).to_string(), "19/7");
+    assert_eq!(format!("{:#}", frac(19, 7)), "2 5/7");
+    assert_eq!(format!("{:#}", frac(5, 7)), "5/7");
+    assert_eq!(format!("{:#}", frac(3, 1)), "3");
+    assert_eq!(format!("{:#}", UFrac8::ZERO), "0");
+    assert_eq!(format!("{:.6}", frac(3, 7)), "0.428571");
+    assert_eq!(format!("{:.3}", UFrac8::MAX), "8.000");
+    assert_eq!(format!("{:.0}", frac(2, 3)), "1");
+    // ties round to even
+    assert_eq!(format!("{:.2}", frac(1, 8)), "0.12");
+    assert_eq!(format!("{:.2}", frac(3, 8)), "0.38");
+    assert_eq!(format!("{:.0}", frac(5, 2)), "2");
+    assert_eq!(format!("{:.0}", frac(7, 2)), "4");
+    assert_eq!(format!("{:.1}", frac(9, 20)), "0.4");
+    assert_eq!(format!("{:+}", frac(3, 7)), "+3/7");
+    assert_eq!(format!("{:>6}", frac(3, 7)), "   3/7");
+    assert_eq!(format!("{:06.2}", frac(3, 8)), "000.38");
+    // decimals round the same way as the float does, wherever the float is exact or can't be near a tie
+    for bits in 0..=u8::MAX {
+        let frac = UFrac8::from_bits(bits);
+        if !frac.to_fraction().1.is_multiple_of(5) {
+            let float = frac.to_f64();
+            for places in 0..5 {
+                assert_eq!(format!("{frac:.places$}"), format!("{float:.places$}"));
+            }
+        }
+    }
+}
+
+#[test]
+fn to_decimal_string() {
+    let frac = |num, den| UFrac8::from_ratio(num, den).unwrap();
+    assert_eq!(frac(1, 7).to_decimal_string(6), "0.(142857)");
+    assert_eq!(frac(1, 7).to_decimal_string(5), "0.14285...");
+    assert_eq!(frac(1, 6).to_decimal_string(10), "0.1(6)");
+    assert_eq!(frac(7, 3).to_decimal_string(10), "2.(3)");
+    assert_eq!(frac(3, 8).to_decimal_string(10), "0.375");
+    assert_eq!(frac(3, 8).to_decimal_string(2), "0.37...");
+    assert_eq!(frac(1, 3).to_decimal_string(0), "0...");
+    assert_eq!(frac(5, 1).to_decimal_string(0), "5");
+    assert_eq!(UFrac8::ZERO.to_decimal_string(3), "0");
+}
+
+#[test]
+fn path_string() {
+    let frac = |num, den| UFrac8::from_ratio(num, den).unwrap();
+    assert_eq!(frac(3, 7).to_path_string(), "LLRR");
+    assert_eq!(UFrac8::ONE.to_path_string(), "");
+    assert_eq!(UFrac8::ZERO.to_path_string(), "0");
+    assert_eq!(UFrac8::MAX.to_path_string(), "RRRRRRR");
+    assert_eq!(UFrac8::MIN.to_path_string(), "LLLLLLL");
+    assert_eq!(UFrac8::from_path_str("LLRR"), Ok(frac(3, 7)));
+    assert_eq!(UFrac8::from_path_str(""), Ok(UFrac8::ONE));
+    assert_eq!(UFrac8::from_path_str("-0"), Ok(UFrac8::ZERO));
+    assert_eq!(
+        UFrac8::from_path_str("LLRRX"),
+        Err(FractionError::InvalidSyntax)
+    );
+    assert_eq!(
+        UFrac8::from_path_str("llrr"),
+        Err(FractionError::InvalidSyntax)
+    );
+    assert_eq!(
+        UFrac8::from_path_str("LLLLLLLL"),
+        Err(FractionError::PrecisionLoss { bits_needed: 9 })
+    );
+    assert_eq!(
+        UFrac8::from_path_str("RRRRRRRL"),
+        Err(FractionError::PrecisionLoss { bits_needed: 9 })
+    );
+    assert_eq!(
+        UFrac8::from_path_str("RRRRRRRR"),
+        Err(FractionError::OutOfRange { max: 8 })
+    );
+    assert_eq!(UFrac8::from_path_str("-L"), Err(FractionError::Negative));
+    for bits in 0..=u8::MAX {
+        let frac = UFrac8::from_bits(bits);
+        let path = frac.to_path_string();
+        assert_eq!(UFrac8::from_path_str(&path), Ok(frac));
+        // the path is the same at any width
+        assert_eq!(UFrac32::from(frac).to_path_string(), path);
+    }
+}
+
+#[test]
+fn bit_formats() {
+    let frac = UFrac8::from_ratio(3, 7).unwrap();
+    assert_eq!(format!("{frac:?}"), "UFrac8(3/7 @ 0b0011_1000)");
+    assert_eq!(format!("{frac:b}"), "111000");
+    assert_eq!(format!("{frac:08b}"), "00111000");
+    assert_eq!(format!("{frac:#b}"), "0b111000");
+    assert_eq!(format!("{frac:o}"), "70");
+    assert_eq!(format!("{frac:x}"), "38");
+    assert_eq!(format!("{:#X}", UFrac8::MAX), "0xFF");
+    assert_eq!(format!("{:?}", UFrac8::ZERO), "UFrac8(0/1 @ 0b0000_0000)");
+}
+
+#[test]
+fn continued_fraction() {
+    let terms = |frac: UFrac8| frac.to_continued_fraction().collect::<Vec<_>>();
+    assert_eq!(terms(UFrac8::from_ratio(3, 7).unwrap()), [0, 2, 3]);
+    assert_eq!(terms(UFrac8::ZERO), [0]);
+    assert_eq!(terms(UFrac8::ONE), [1]);
+    assert_eq!(terms(UFrac8::MAX), [8]);
+    assert_eq!(terms(UFrac8::MIN), [0, 8]);
+    assert_eq!(UFrac8::from_continued_fraction(&[0]), Ok(UFrac8::ZERO));
+    assert_eq!(
+        UFrac8::from_continued_fraction(&[]),
+        Err(FractionError::Infinite)
+    );
+    assert_eq!(
+        UFrac8::from_continued_fraction(&[3, 0]),
+        Err(FractionError::Infinite)
+    );
+    // zero terms fold into their neighbours
+    assert_eq!(
+        UFrac8::from_continued_fraction(&[1, 0, 2]),
+        UFrac8::from_continued_fraction(&[3])
+    );
+    assert_eq!(
+        UFrac8::from_continued_fraction(&[2, 3, 0]),
+        UFrac8::from_continued_fraction(&[2])
+    );
+    assert_eq!(
+        UFrac8::from_continued_fraction(&[0, 2, 2, 1]),
+        UFrac8::from_continued_fraction(&[0, 2, 3])
+    );
+    assert_eq!(
+        UFrac8::from_continued_fraction(&[u32::MAX]),
+        Ok(UFrac8::MAX)
+    );
+    assert_eq!(
+        UFrac8::from_continued_fraction(&[0, u32::MAX]),
+        Ok(UFrac8::ZERO)
+    );
+    for bits in 0..=u8::MAX {
+        let frac = UFrac8::from_bits(bits);
+        assert_eq!(UFrac8::from_continued_fraction(&terms(frac)), Ok(frac));
+    }
+    // every short continued fraction rounds the same way as its ratio
+    let mut stack = vec![vec![]];
+    while let Some(prefix) = stack.pop() {
+        for term in 0..=20 {
+            let mut terms = prefix.clone();
+            terms.push(term);
+            let (num, den) = terms
+                .iter()
+                .rev()
+                .fold((1, 0), |(num, den), &term| (term * num + den, num));
+            let (Ok(num), Ok(den)) = (u8::try_from(num), u8::try_from(den)) else {
+                continue;
+            };
+            if den == 0 {
+                continue;
+            }
+            assert_eq!(
+                UFrac8::from_continued_fraction(&terms),
+                UFrac8::nearest_ratio(num, den),
+                "{terms:?}"
+            );
+            if terms.len() < 5 {
+                stack.push(terms);
+            }
+        }
+    }
+}
+
+#[test]
+fn next_up_and_down() {
+    let mut values: Vec<UFrac8> = (0..=u8::MAX).map(UFrac8::from_bits).collect();
+    values.sort_by(|a, b| {
+        let ((a_num, a_den), (b_num, b_den)) = (a.to_fraction(), b.to_fraction());
+        (u16::from(a_num) * u16::from(b_den)).cmp(&(u16::from(b_num) * u16::from(a_den)))
+    });
+    for pair in values.windows(2) {
+        assert_eq!(pair[0].next_up(), Some(pair[1]));
+        assert_eq!(pair[1].next_down(), Some(pair[0]));
+    }
+    assert_eq!(UFrac8::MAX.next_up(), None);
+    assert_eq!(UFrac8::ZERO.next_down(), None);
+    assert_eq!(UFrac8::ZERO.next_up(), Some(UFrac8::MIN));
+    assert_eq!(UFrac8::ONE.next_up().unwrap().to_fraction(), (8, 7));
+    assert_eq!(UFrac32::MAX.next_down().unwrap().to_fraction(), (31, 1));
+}
+
+#[test]
+fn bounds() {
+    assert_eq!(
+        "3/7".parse::<UFrac8>().unwrap().bounds(),
+        ((2, 5), (3, 7), (1, 2))
+    );
+    assert_eq!(UFrac8::ONE.bounds(), ((0, 1), (1, 1), (1, 0)));
+    assert_eq!(UFrac8::MAX.bounds(), ((7, 1), (8, 1), (1, 0)));
+    assert_eq!(UFrac8::MIN.bounds(), ((0, 1), (1, 8), (1, 7)));
+    assert_eq!(UFrac8::ZERO.bounds(), ((0, 1), (0, 1), (0, 1)));
+    for bits in 1..=u8::MAX {
+        let frac = UFrac8::from_bits(bits);
+        let (lower, mid, upper) = frac.bounds();
+        assert_eq!(mid, frac.to_fraction());
+        // each child covers one side of its parent's interval
+        if let Some((left, right)) = frac.children() {
+            assert_eq!(left.bounds(), (lower, left.to_fraction(), mid));
+            assert_eq!(right.bounds(), (mid, right.to_fraction(), upper));
+        }
+    }
+}
+
+#[test]
+fn simplest_between() {
+    let third = UFrac32::simplest_between(
+        UFrac32::try_from(0.33).unwrap(),
+        UFrac32::try_from(0.34).unwrap(),
+    );
+    assert_eq!(third.map(UFrac32::to_fraction), Some((1, 3)));
+    assert_eq!(
+        UFrac8::simplest_between(UFrac8::ZERO, UFrac8::MAX),
+        Some(UFrac8::ONE)
+    );
+    assert_eq!(UFrac8::simplest_between(UFrac8::ONE, UFrac8::ONE), None);
+    assert_eq!(
+        UFrac8::simplest_between_inclusive(UFrac8::ONE, UFrac8::ONE),
+        UFrac8::ONE
+    );
+    // every pair, against the shallowest of the values between them
+    let values: Vec<(UFrac8, u16, u16)> = (0..=u8::MAX)
+        .map(|bits| {
+            let frac = UFrac8::from_bits(bits);
+            let (num, den) = frac.to_fraction();
+            (frac, num.into(), den.into())
+        })
+        .collect();
+    let is_below = |(_, a_num, a_den): (UFrac8, u16, u16),
+                    (_, b_num, b_den): (UFrac8, u16, u16)| {
+        a_num * b_den < b_num * a_den
+    };
+    for &a in &values {
+        for &b in &values {
+            let (lower, upper) = if is_below(b, a) { (b, a) } else { (a, b) };
+            let strictly_between = values
+                .iter()
+                .filter(|&&value| is_below(lower, value) && is_below(value, upper))
+                .map(|&(frac, _, _)| frac)
+                .min_by_key(|&frac| frac.precision());
+            assert_eq!(UFrac8::simplest_between(a.0, b.0), strictly_between);
+            let inclusive = values
+                .iter()
+                .filter(|&&value| !is_below(value, lower) && !is_below(upper, value))
+                .map(|&(frac, _, _)| frac)
+                .min_by_key(|&frac| (frac != UFrac8::ZERO, frac.precision()));
+            assert_eq!(
+                UFrac8::simplest_between_inclusive(a.0, b.0),
+                inclusive.unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+fn common_ancestor() {
+    let (a, b) = (
+        "3/7".parse::<UFrac8>().unwrap(),
+        "2/5".parse::<UFrac8>().unwrap(),
+    );
+    assert_eq!(UFrac8::common_ancestor(a, b), Some(b));
+    assert_eq!(UFrac8::tree_distance(a, b), Some(1));
+    assert_eq!(
+        UFrac8::common_ancestor(UFrac8::MIN, UFrac8::MAX),
+        Some(UFrac8::ONE)
+    );
+    assert_eq!(UFrac8::tree_distance(UFrac8::MIN, UFrac8::MAX), Some(14));
+    assert_eq!(UFrac8::common_ancestor(UFrac8::ZERO, UFrac8::ONE), None);
+    assert_eq!(UFrac8::tree_distance(UFrac8::ONE, UFrac8::ZERO), None);
+    // every pair, against walking up from each with `parent`
+    let ancestors =
+        |frac: UFrac8| std::iter::successors(Some(frac), |&frac| frac.parent()).collect::<Vec<_>>();
+    for a in (1..=u8::MAX).map(UFrac8::from_bits) {
+        let a_ancestors = ancestors(a);
+        for b in (1..=u8::MAX).map(UFrac8::from_bits) {
+            let b_ancestors = ancestors(b);
+            let ancestor = a_ancestors
+                .iter()
+                .copied()
+                .find(|ancestor| b_ancestors.contains(ancestor))
+                .unwrap();
+            assert_eq!(UFrac8::common_ancestor(a, b), Some(ancestor));
+            assert_eq!(
+                UFrac8::tree_distance(a, b),
+                Some(a.precision() + b.precision() - 2 * ancestor.precision())
+            );
+        }
+    }
+}
+
+#[test]
+fn ancestors_and_path_from_root() {
+    let frac = "3/7".parse::<UFrac8>().unwrap();
+    let fractions = |fracs: Vec<UFrac8>| {
+        fracs
+            .into_iter()
+            .map(UFrac8::to_fraction)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        fractions(frac.ancestors().collect()),
+        [(3, 7), (2, 5), (1, 3), (1, 2), (1, 1)]
+    );
+    let (nodes, directions): (Vec<_>, Vec<_>) = frac.path_from_root().unzip();
+    assert_eq!(fractions(nodes), [(1, 2), (1, 3), (2, 5), (3, 7)]);
+    assert_eq!(
+        directions,
+        [
+            Direction::Left,
+            Direction::Left,
+            Direction::Right,
+            Direction::Right
+        ]
+    );
+    assert_eq!(UFrac8::ZERO.ancestors().count(), 0);
+    assert_eq!(UFrac8::ZERO.path_from_root().count(), 0);
+    assert_eq!(UFrac8::ONE.ancestors().collect::<Vec<_>>(), [UFrac8::ONE]);
+    assert_eq!(UFrac8::ONE.path_from_root().count(), 0);
+    for frac in (1..=u8::MAX).map(UFrac8::from_bits) {
+        let ancestors: Vec<UFrac8> = frac.ancestors().collect();
+        assert_eq!(ancestors.len(), frac.precision() as usize + 1);
+        // the path down visits the same nodes, without the root, and turns as the path string does
+        let (mut nodes, directions): (Vec<_>, Vec<_>) = frac.path_from_root().unzip();
+        nodes.insert(0, UFrac8::ONE);
+        nodes.reverse();
+        assert_eq!(nodes, ancestors);
+        let path: String = directions
+            .iter()
+            .map(|&direction| {
+                if direction == Direction::Right {
+                    'R'
+                } else {
+                    'L'
+                }
+            })
+            .collect();
+        assert_eq!(path, frac.to_path_string());
+    }
+}
@@ -1,9 +1,21 @@
-mod ufrac16;
-mod ufrac32;
-mod ufrac64;
-mod ufrac8;
+#[cfg(feature = "big")]
+mod big;
+mod constants;
+mod ufrac;
+mod width;
 
-pub use ufrac16::UFrac16;
-pub use ufrac32::UFrac32;
-pub use ufrac64::UFrac64;
-pub use ufrac8::UFrac8;
+#[cfg(feature = "big")]
+pub use big::BigUFrac;
+pub use ufrac::UFrac;
+pub use width::{BitWidth, Width};
+
+/// A fraction defined along a binary tree, in 8 bits; up to 7 steps deep
+pub type UFrac8 = UFrac<8>;
+/// A fraction defined along a binary tree, in 16 bits; up to 15 steps deep
+pub type UFrac16 = UFrac<16>;
+/// A fraction defined along a binary tree, in 32 bits; up to 31 steps deep
+pub type UFrac32 = UFrac<32>;
+/// A fraction defined along a binary tree, in 64 bits; up to 63 steps deep
+pub type UFrac64 = UFrac<64>;
+/// A fraction defined along a binary tree, in 128 bits; up to 127 steps deep
+pub type UFrac128 = UFrac<128>;
@@ -0,0 +1,508 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
+    str::FromStr,
+};
+
+use num_bigint::BigUint;
+
+use super::{
+    width::{BitWidth, Width},
+    UFrac,
+};
+use crate::{
+    format::{write_debug, write_ratio},
+    parse::parse,
+    Direction, FractionError, IFrac16, IFrac32, IFrac64, IFrac8,
+};
+
+/// A fraction defined along a binary tree, with a path of any length on the heap.
+///
+/// The bits are laid out the same way as in a [`UFrac`], but with no fixed width: the path from the most significant bit down, `1` for a step to the right, followed by a terminating `1` bit. Where a `UFrac` pads the pattern with zeros, a `BigUFrac` simply ends. `0` is the empty pattern.
+///
+/// Every positive rational number has a `BigUFrac`, so `to_fraction` and `from_fraction` round-trip exactly.
+#[derive(PartialEq, Eq, Default, Clone, Hash)]
+pub struct BigUFrac {
+    /// The bit pattern, most significant bit of the first word first. Bits past `len` are always `0`.
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Debug for BigUFrac {
+    /// Writes the value and the bit pattern, like `BigUFrac(3/7 @ 0b0_0111)`. Use `{:b}` for the bits alone.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_debug(f, "BigUFrac", self, &self.bit_string())
+    }
+}
+
+macro_rules! bit_formats {
+    ($($format:ident $prefix:literal $bits_per_digit:literal $digits:literal),*) => {$(
+        impl $format for BigUFrac {
+            /// Writes the bit pattern, which is empty for `0`. There's a digit for every bit of the pattern, or part of one at the front, so the path's leading zeros are kept.
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.pad_integral(true, $prefix, &self.digit_string($bits_per_digit, $digits))
+            }
+        }
+    )*};
+}
+
+bit_formats!(
+    Binary "0b" 1 b"01",
+    Octal "0o" 3 b"01234567",
+    LowerHex "0x" 4 b"0123456789abcdef",
+    UpperHex "0x" 4 b"0123456789ABCDEF"
+);
+
+impl Display for BigUFrac {
+    /// Writes `num/den`, or a mixed number like `2 5/7` with `{:#}`, or a decimal rounded to the given number of places with `{:.6}`. Decimals are rounded once from the exact value, with ties to even.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (num, denom) = self.to_fraction();
+        write_ratio(f, false, &num, &denom)
+    }
+}
+
+impl FromStr for BigUFrac {
+    type Err = FractionError;
+    /// Parse an integer like `"3"`, a ratio like `"3/7"`, a mixed number like `"1 1/2"` or a decimal like `"0.375"`, of any length.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        // a `BigUint` holds a literal of any length, so there's no range for it to go past
+        let (is_negative, num, den) = parse::<BigUint>(text, u32::MAX, false)?;
+        if is_negative && num != BigUint::ZERO {
+            return Err(FractionError::Negative);
+        }
+        Self::from_fraction(&num, &den)
+    }
+}
+
+impl PartialOrd for BigUFrac {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUFrac {
+    /// Bit patterns compare the same way the values do, once the shorter one is padded with zeros.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let shared = self.words.len().min(other.words.len());
+        self.words[..shared]
+            .cmp(&other.words[..shared])
+            .then_with(|| {
+                let longer_is_zero = |words: &[u64]| words[shared..].iter().all(|&word| word == 0);
+                match (longer_is_zero(&self.words), longer_is_zero(&other.words)) {
+                    (true, true) => Ordering::Equal,
+                    (false, _) => Ordering::Greater,
+                    (_, false) => Ordering::Less,
+                }
+            })
+    }
+}
+
+impl BigUFrac {
+    pub const ZERO: Self = Self {
+        words: Vec::new(),
+        len: 0,
+    };
+
+    /// `1`, at the root of the tree
+    #[must_use]
+    pub fn one() -> Self {
+        let mut one = Self::ZERO;
+        one.push(true);
+        one
+    }
+
+    /// The exact value of `num/denom`.
+    ///
+    /// # Errors
+    /// `FractionError::Infinite` if `denom` is `0`
+    ///
+    /// # Panics
+    /// If the path is more than `u64::MAX` steps long, which would never fit in memory anyway
+    pub fn from_fraction(num: &BigUint, denom: &BigUint) -> Result<Self, FractionError> {
+        let zero = BigUint::ZERO;
+        if *denom == zero {
+            return Err(FractionError::Infinite);
+        }
+        let mut frac = Self::ZERO;
+        if *num == zero {
+            return Ok(frac);
+        }
+        let (mut num, mut denom) = (num.clone(), denom.clone());
+        // each run of steps in the same direction is one step of Euclid's algorithm
+        while num != denom {
+            let right = num > denom;
+            let (larger, smaller) = if right {
+                (&mut num, &denom)
+            } else {
+                (&mut denom, &num)
+            };
+            let steps = (&*larger - 1u32) / smaller;
+            *larger -= &steps * smaller;
+            let steps = u64::try_from(steps).expect("paths this long don't fit in memory");
+            for _ in 0..steps {
+                frac.push(right);
+            }
+        }
+        frac.push(true);
+        Ok(frac)
+    }
+
+    /// Convert a `BigUFrac` into its exact numerator and denominator.
+    #[must_use]
+    pub fn to_fraction(&self) -> (BigUint, BigUint) {
+        let (_, mid, _) = self.bounds();
+        mid
+    }
+
+    /// The open interval the subtree under `self` covers, as `(lower, self, upper)`, each a numerator and denominator. The bounds are the nearest ancestors on either side, `0/1` and `1/0` standing in for the ends of the tree, so `3/7` gives `(2/5, 3/7, 1/2)`. `0` isn't on the tree, so all three of its ratios are `0/1`.
+    ///
+    /// The values strictly between the bounds are exactly those whose paths down the tree pass through `self`.
+    #[must_use]
+    pub fn bounds(&self) -> ((BigUint, BigUint), (BigUint, BigUint), (BigUint, BigUint)) {
+        if self.len == 0 {
+            let zero = || (BigUint::ZERO, BigUint::from(1u32));
+            return (zero(), zero(), zero());
+        }
+        let (mut lower_num, mut lower_denom) = (BigUint::ZERO, BigUint::from(1u32));
+        let (mut upper_num, mut upper_denom) = (BigUint::from(1u32), BigUint::ZERO);
+        // walk the path a run of steps at a time, so each run costs one multiplication
+        let mut index = 0;
+        while index < self.precision() {
+            let right = self.bit(index);
+            let mut steps = 0u64;
+            while index < self.precision() && self.bit(index) == right {
+                steps += 1;
+                index += 1;
+            }
+            if right {
+                lower_num += &upper_num * steps;
+                lower_denom += &upper_denom * steps;
+            } else {
+                upper_num += &lower_num * steps;
+                upper_denom += &lower_denom * steps;
+            }
+        }
+        let mid = (&lower_num + &upper_num, &lower_denom + &upper_denom);
+        ((lower_num, lower_denom), mid, (upper_num, upper_denom))
+    }
+
+    /// The path down the Farey tree to `self`, as `L` and `R` steps from the root, like `"LLRR"` for `3/7`. The path to `1` is empty, and `0`, which isn't on the tree, is `"0"`.
+    #[must_use]
+    pub fn to_path_string(&self) -> String {
+        if self.len == 0 {
+            return "0".to_owned();
+        }
+        (0..self.precision())
+            .map(|index| if self.bit(index) { 'R' } else { 'L' })
+            .collect()
+    }
+
+    /// Follow a path of `L` and `R` steps down from the root, as written by [`to_path_string`](Self::to_path_string).
+    ///
+    /// # Errors
+    /// `FractionError::InvalidSyntax` if the path has anything but `L`s and `R`s, and `FractionError::Negative` if it starts with a `-`
+    pub fn from_path_str(path: &str) -> Result<Self, FractionError> {
+        let (is_negative, path) = path
+            .strip_prefix('-')
+            .map_or((false, path), |path| (true, path));
+        if path == "0" {
+            return Ok(Self::ZERO);
+        }
+        let mut frac = Self::ZERO;
+        for step in path.bytes() {
+            match step {
+                b'L' => frac.push(false),
+                b'R' => frac.push(true),
+                _ => return Err(FractionError::InvalidSyntax),
+            }
+        }
+        if is_negative {
+            return Err(FractionError::Negative);
+        }
+        frac.push(true);
+        Ok(frac)
+    }
+
+    /// Get the precision of a value: how many steps down the Farey tree the fraction is.
+    /// If `self` is equal to `0` or `1`, this function will return `0`.
+    #[must_use]
+    pub const fn precision(&self) -> usize {
+        self.len.saturating_sub(1)
+    }
+
+    /// The inverse of a `BigUFrac`. For any nonzero value, `self.invert().invert()` is guaranteed to be equal to `self`.
+    ///
+    /// # Panics
+    /// If `self` is equal to `0`
+    #[must_use]
+    pub fn invert(&self) -> Self {
+        self.try_invert().expect("Can't invert `0/1`")
+    }
+
+    /// The inverse of a `BigUFrac`. If `self` is equal to `0`, returns `None`.
+    #[must_use]
+    pub fn try_invert(&self) -> Option<Self> {
+        if self.len == 0 {
+            return None;
+        }
+        let mut inverse = self.clone();
+        for index in 0..self.precision() {
+            inverse.set(index, !self.bit(index));
+        }
+        Some(inverse)
+    }
+
+    /// Get the fraction's parent node on the Farey tree. Returns `None` if `self` is 0 or 1.
+    #[must_use]
+    pub fn parent(&self) -> Option<Self> {
+        if self.len <= 1 {
+            return None;
+        }
+        // drop the terminating bit, then turn the last step into the new one
+        let mut parent = self.clone();
+        parent.pop();
+        parent.set(parent.len - 1, true);
+        Some(parent)
+    }
+
+    /// Iterate over `self` and its ancestors on the Farey tree, walking up with [`parent`](Self::parent) to `1`, which comes last. `0` isn't on the tree, so its iterator is empty.
+    pub fn ancestors(&self) -> impl Iterator<Item = Self> {
+        std::iter::successors((self.len > 0).then(|| self.clone()), Self::parent)
+    }
+
+    /// Iterate over the steps down the Farey tree from `1` to `self`, each as the node it reaches and the direction it goes, so `3/7` gives `1/2`, `1/3`, `2/5` and `3/7`, going left, left, right and right. The path to `1` and to `0` has no steps.
+    pub fn path_from_root(&self) -> impl Iterator<Item = (Self, Direction)> + '_ {
+        (0..self.precision()).scan(Self::one(), |node, index| {
+            let right = self.bit(index);
+            *node = node.child(right)?;
+            Some((node.clone(), Direction::from_bit(right)))
+        })
+    }
+
+    /// Get the fraction's left child node on the Farey tree. Returns `None` if called on `0`.
+    #[must_use]
+    pub fn left_child(&self) -> Option<Self> {
+        self.child(false)
+    }
+
+    /// Get the fraction's right child node on the Farey tree. Returns `None` if called on `0`.
+    #[must_use]
+    pub fn right_child(&self) -> Option<Self> {
+        self.child(true)
+    }
+
+    /// Get the fraction's child nodes on the Farey tree. Returns `None` if called on `0`.
+    ///
+    /// Equivalent to `(self.left_child()?,self.right_child()?)`
+    #[must_use]
+    pub fn children(&self) -> Option<(Self, Self)> {
+        Some((self.left_child()?, self.right_child()?))
+    }
+
+    /// Get the other child of the fraction's parent node on the Farey tree. Returns `None` if called on `0` or `1`.
+    #[must_use]
+    pub fn sibling(&self) -> Option<Self> {
+        if self.len <= 1 {
+            return None;
+        }
+        let mut sibling = self.clone();
+        let last_step = self.len - 2;
+        sibling.set(last_step, !self.bit(last_step));
+        Some(sibling)
+    }
+
+    /// Get the deepest node on the Farey tree that both `a` and `b` lie under, each counting as under itself, so the common ancestor of `3/7` and `2/5` is `2/5`. Returns `None` if either is `0`, which isn't on the tree.
+    #[must_use]
+    pub fn common_ancestor(a: &Self, b: &Self) -> Option<Self> {
+        let shared = Self::shared_steps(a, b)?;
+        let mut ancestor = Self::default();
+        for index in 0..shared {
+            ancestor.push(a.bit(index));
+        }
+        ancestor.push(true);
+        Some(ancestor)
+    }
+
+    /// Get how many parent and child steps apart `a` and `b` are on the Farey tree: up from one to their [`common_ancestor`](Self::common_ancestor) and down to the other. Returns `None` if either is `0`, which isn't on the tree.
+    #[must_use]
+    pub fn tree_distance(a: &Self, b: &Self) -> Option<usize> {
+        let shared = Self::shared_steps(a, b)?;
+        Some(a.precision() + b.precision() - 2 * shared)
+    }
+
+    /// How many steps the paths to `a` and `b` have in common, or `None` if either is `0`.
+    fn shared_steps(a: &Self, b: &Self) -> Option<usize> {
+        if a.len == 0 || b.len == 0 {
+            return None;
+        }
+        // the paths agree up to the first bit the patterns differ in, or up to where the shorter one ends
+        let first_difference =
+            a.words
+                .iter()
+                .zip(&b.words)
+                .enumerate()
+                .find_map(|(index, (a_word, b_word))| {
+                    let difference = a_word ^ b_word;
+                    (difference != 0).then(|| index * 64 + difference.leading_zeros() as usize)
+                });
+        Some(
+            first_difference
+                .unwrap_or(usize::MAX)
+                .min(a.precision())
+                .min(b.precision()),
+        )
+    }
+
+    fn child(&self, right: bool) -> Option<Self> {
+        if self.len == 0 {
+            return None;
+        }
+        let mut child = self.clone();
+        child.set(self.len - 1, right);
+        child.push(true);
+        Some(child)
+    }
+
+    /// The bit pattern as `0`s and `1`s.
+    fn bit_string(&self) -> String {
+        self.digit_string(1, b"01")
+    }
+
+    /// The bit pattern as a number in base `2^bits_per_digit`, written with `digits`, padded with zeros at the front to a whole number of digits.
+    fn digit_string(&self, bits_per_digit: usize, digits: &[u8]) -> String {
+        let padding = self.len.div_ceil(bits_per_digit) * bits_per_digit - self.len;
+        (0..self.len + padding)
+            .step_by(bits_per_digit)
+            .map(|start| {
+                let digit = (start..start + bits_per_digit).fold(0, |digit, index| {
+                    (digit << 1) | usize::from(index >= padding && self.bit(index - padding))
+                });
+                char::from(digits[digit])
+            })
+            .collect()
+    }
+
+    /// Whether `self` is greater than the integer `max`, so its path starts with `max` steps right.
+    fn exceeds(&self, max: u32) -> bool {
+        let max = max as usize;
+        self.precision() >= max && (0..max).all(|index| self.bit(index))
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        (self.words[index / 64] >> (63 - index % 64)) & 1 == 1
+    }
+
+    fn set(&mut self, index: usize, bit: bool) {
+        let mask = 1 << (63 - index % 64);
+        if bit {
+            self.words[index / 64] |= mask;
+        } else {
+            self.words[index / 64] &= !mask;
+        }
+    }
+
+    fn push(&mut self, bit: bool) {
+        if self.len.is_multiple_of(64) {
+            self.words.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, bit);
+    }
+
+    fn pop(&mut self) {
+        self.set(self.len - 1, false);
+        self.len -= 1;
+        if self.len.is_multiple_of(64) {
+            self.words.pop();
+        }
+    }
+}
+
+impl<const BITS: u32> From<UFrac<BITS>> for BigUFrac
+where
+    Width<BITS>: BitWidth,
+{
+    fn from(value: UFrac<BITS>) -> Self {
+        let bits: u128 = value.to_bits().into();
+        let mut frac = Self::ZERO;
+        if bits != 0 {
+            let len = BITS - bits.trailing_zeros();
+            for index in 0..len {
+                frac.push((bits >> (BITS - 1 - index)) & 1 == 1);
+            }
+        }
+        frac
+    }
+}
+
+impl<const BITS: u32> TryFrom<BigUFrac> for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    type Error = FractionError;
+    /// Try to fit a `BigUFrac` into a `UFrac`. Fails if passed a value greater than `BITS`, or one with `BITS` or more bits of precision.
+    fn try_from(value: BigUFrac) -> Result<Self, Self::Error> {
+        if value.exceeds(BITS) {
+            return Err(FractionError::OutOfRange { max: BITS });
+        }
+        if value.len > BITS as usize {
+            return Err(FractionError::PrecisionLoss {
+                bits_needed: value.len as u128,
+            });
+        }
+        let bits = (0..value.len).fold(0u128, |bits, index| {
+            bits | (u128::from(value.bit(index)) << (BITS as usize - 1 - index))
+        });
+        Ok(Self::from_bits(<Width<BITS> as BitWidth>::narrow(bits)))
+    }
+}
+
+/// Conversions to and from the signed types, which keep the path of the magnitude from the least significant bit up, ended by a `1`.
+macro_rules! signed_conversions {
+    ($($frac:ident($bits:ident): $max:literal $max_depth:literal)*) => {$(
+        impl TryFrom<$frac> for BigUFrac {
+            type Error = FractionError;
+            #[doc = concat!("Try to convert an `", stringify!($frac), "` into a `BigUFrac`. Fails if passed a negative value.")]
+            fn try_from(value: $frac) -> Result<Self, Self::Error> {
+                let magnitude = value.abs().to_bits();
+                if magnitude == 0 {
+                    return Ok(Self::ZERO);
+                }
+                if value.is_negative() {
+                    return Err(FractionError::Negative);
+                }
+                let mut frac = Self::ZERO;
+                for index in 0..value.precision() {
+                    frac.push((magnitude >> index) & 1 == 1);
+                }
+                frac.push(true);
+                Ok(frac)
+            }
+        }
+
+        impl TryFrom<BigUFrac> for $frac {
+            type Error = FractionError;
+            #[doc = concat!("Try to fit a `BigUFrac` into an `", stringify!($frac), "`. Fails if passed a value greater than ", $max, ", or one with ", $max, " or more bits of precision.")]
+            fn try_from(value: BigUFrac) -> Result<Self, Self::Error> {
+                if value.len == 0 {
+                    return Ok(Self::ZERO);
+                }
+                if value.exceeds($max) {
+                    return Err(FractionError::OutOfRange { max: $max });
+                }
+                let precision = value.precision();
+                if precision > $max_depth {
+                    return Err(FractionError::PrecisionLoss {
+                        bits_needed: precision as u128 + 2,
+                    });
+                }
+                let magnitude = (0..precision).fold(1 << precision, |bits: $bits, index| {
+                    bits | ($bits::from(value.bit(index)) << index)
+                });
+                Ok(Self::from_bits(magnitude))
+            }
+        }
+    )*};
+}
+
+signed_conversions!(IFrac8(u8): 7 6 IFrac16(u16): 15 14 IFrac32(u32): 31 30 IFrac64(u64): 63 62);
@@ -0,0 +1,46 @@
+use super::UFrac;
+
+impl UFrac<8> {
+    /// The Golden Ratio approximated as a `UFrac8`; equal to 21/13 or 1.61538461538
+    pub const GOLDEN_RATIO: Self = Self::from_bits(0b1010_1010);
+    /// Euler's Number approximated as a `UFrac8`; equal to 19/7 or 2.71428571429
+    pub const E: Self = Self::from_bits(0b1101_1010);
+    /// Pi approximated as a `UFrac8`; equal to 16/5 or 3.2
+    pub const PI: Self = Self::from_bits(0b1110_0001);
+}
+
+impl UFrac<16> {
+    /// The Golden Ratio approximated as a `UFrac16`; equal to 987/610, or 1.61803278689
+    pub const GOLDEN_RATIO: Self = Self::from_bits(0xAAAA);
+    /// Euler's Number approximated as a `UFrac16`; equal to 791/291, or 2.71821305842
+    pub const E: Self = Self::from_bits(0xDA15);
+    /// Pi approximated as a `UFrac16`; equal to 204/65, or 3.13846153846
+    pub const PI: Self = Self::from_bits(0xE03D);
+}
+
+impl UFrac<32> {
+    /// The Golden Ratio approximated as a `UFrac32`; equal to 2178309/1346269 or 1.61803398875
+    pub const GOLDEN_RATIO: Self = Self::from_bits(0xAAAA_AAAA);
+    /// Euler's Number approximated as a `UFrac32`; equal to 28667/10546 or 2.71828181301
+    pub const E: Self = Self::from_bits(0xDA17_E802);
+    /// Pi approximated as a `UFrac32`; equal to 3861/1229 or 3.14157851912
+    pub const PI: Self = Self::from_bits(0xE03F_FFBD);
+}
+
+impl UFrac<64> {
+    /// The Golden Ratio approximated as a `UFrac64`; equal to 10610209857723/6557470319842 or 1.61803398875
+    pub const GOLDEN_RATIO: Self = Self::from_bits(0xAAAA_AAAA_AAAA_AAAA);
+    /// Euler's Number approximated as a `UFrac64`; equal to 340033231/125091235 or 2.718281828459044
+    pub const E: Self = Self::from_bits(0xDA17_E805_FFA0_017D);
+    /// Pi approximated as a `UFrac64`; equal to 26581/8461 or 3.1415908285072685
+    pub const PI: Self = Self::from_bits(0xE03F_FFBF_FFFF_FFFD);
+}
+
+impl UFrac<128> {
+    /// The Golden Ratio approximated as a `UFrac128`; equal to 251728825683549488150424261/155576970220531065681649693 or 1.618033988749895
+    pub const GOLDEN_RATIO: Self = Self::from_bits(0xAAAA_AAAA_AAAA_AAAA_AAAA_AAAA_AAAA_AAAA);
+    /// Euler's Number approximated as a `UFrac128`; equal to 19231667418158/7074935062587 or 2.718281828459045
+    pub const E: Self = Self::from_bits(0xDA17_E805_FFA0_017F_FE80_005F_FFFA_0001);
+    /// Pi approximated as a `UFrac128`; equal to 355/113 or 3.1415929203539825
+    pub const PI: Self = Self::from_bits(0xE03F_FFC0_0000_0000_0000_0000_0000_0000);
+}
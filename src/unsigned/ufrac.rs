@@ -0,0 +1,926 @@
+use std::{
+    fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use super::width::{BitWidth, Width};
+use crate::{
+    farey::{
+        common_ancestor, continued_fraction, continued_fraction_bits, ifrac_exceeds,
+        ifrac_to_ufrac_bits, parse_path, path_string, ufrac_exceeds, ufrac_to_ifrac_bits, Descent,
+    },
+    float::{descend, ratio_to_f32, ratio_to_f64, round, Float, F16},
+    format::{decimal_string, write_debug, write_ratio},
+    gosper::{Bihomographic, Operand},
+    parse::{exact_bits, parse},
+    wide::U256,
+    Direction, FareyFraction, FractionError, IFrac8, RoundingMode, UFrac16, UFrac32, UFrac64,
+    UFrac8,
+};
+
+/// A fraction defined along a binary tree, in `BITS` bits. `UFrac8`, `UFrac16`, `UFrac32`, `UFrac64` and `UFrac128` are aliases of this type, and any other width from 2 to 128 bits works the same way.
+///
+/// The path down the tree is stored from the most significant bit down, `1` for a step to the right, followed by a terminating `1` bit. A `UFrac<BITS>` can be at most `BITS - 1` steps deep.
+///
+/// 0s, 1, xs
+///
+/// `0bxxxx_x100`
+///
+/// `0bxxxx_xxx1`
+#[derive(PartialEq, Eq, Default, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct UFrac<const BITS: u32>(<Width<BITS> as BitWidth>::Bits)
+where
+    Width<BITS>: BitWidth;
+
+/// A numerator and denominator in the integer type of a `UFrac<BITS>`.
+type Ratio<const BITS: u32> = (
+    <Width<BITS> as BitWidth>::Bits,
+    <Width<BITS> as BitWidth>::Bits,
+);
+
+impl<const BITS: u32> Debug for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    /// Writes the value and the bit pattern, like `UFrac8(3/7 @ 0b0011_1000)`. Use `{:b}` for the bits alone.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match BITS {
+            8 | 16 | 32 | 64 | 128 => format!("UFrac{BITS}"),
+            _ => format!("UFrac<{BITS}>"),
+        };
+        let bits = format!("{:0>width$b}", self.0, width = BITS as usize);
+        write_debug(f, &name, self, &bits)
+    }
+}
+
+impl<const BITS: u32> Display for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    /// Writes `num/den`, or a mixed number like `2 5/7` with `{:#}`, or a decimal rounded to the given number of places with `{:.6}`. Decimals are rounded once from the exact value, with ties to even.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (num, denom) = self.to_fraction();
+        write_ratio::<u128>(f, false, &num.into(), &denom.into())
+    }
+}
+
+impl<const BITS: u32> FromStr for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    type Err = FractionError;
+    /// Parse an integer like `"3"`, a ratio like `"3/7"`, a mixed number like `"1 1/2"` or a decimal like `"0.375"`, which must be exactly representable. Decimals are read exactly, not through a float.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (is_negative, num, den) = parse::<U256>(text, BITS, false)?;
+        if is_negative && !num.is_zero() {
+            return Err(FractionError::Negative);
+        }
+        exact_bits(num, den, BITS, Self::MAX_DEPTH).map(Self::from_wide)
+    }
+}
+
+impl<const BITS: u32> UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    pub const ZERO: Self = Self(<Width<BITS> as BitWidth>::ZERO);
+    /// Lowest non-zero value; equal to `1/BITS`
+    pub const MIN: Self = Self(<Width<BITS> as BitWidth>::MIN);
+    pub const ONE: Self = Self(<Width<BITS> as BitWidth>::ONE);
+    /// Highest value; equal to `BITS`
+    pub const MAX: Self = Self(<Width<BITS> as BitWidth>::MAX);
+
+    /// How many steps down the Farey tree a value can be
+    const MAX_DEPTH: u32 = BITS - 1;
+
+    /// Convert a `UFrac` into its numerator and denominator.
+    #[must_use]
+    pub fn to_fraction(
+        self,
+    ) -> (
+        <Width<BITS> as BitWidth>::Bits,
+        <Width<BITS> as BitWidth>::Bits,
+    ) {
+        self.bounds().1
+    }
+
+    /// The open interval the subtree under `self` covers, as `(lower, self, upper)`, each a numerator and denominator. The bounds are the nearest ancestors on either side, `0/1` and `1/0` standing in for the ends of the tree, so `3/7` gives `(2/5, 3/7, 1/2)` and `1` gives `(0/1, 1/1, 1/0)`. `0` isn't on the tree, so all three of its ratios are `0/1`.
+    ///
+    /// The values strictly between the bounds are exactly those whose paths down the tree pass through `self`.
+    #[must_use]
+    pub fn bounds(self) -> (Ratio<BITS>, Ratio<BITS>, Ratio<BITS>) {
+        let bits = self.wide();
+        if bits == 0 {
+            let zero = (Self::narrow(0), Self::narrow(1));
+            return (zero, zero, zero);
+        }
+        let mut lower_num = 0;
+        let mut lower_denom = 1;
+        let mut mid_num = 1;
+        let mut mid_denom = 1;
+        let mut upper_num = 1;
+        let mut upper_denom = 0;
+        for i in 0..self.precision() {
+            if bits & (1 << (Self::MAX_DEPTH - i)) == 0 {
+                upper_num = mid_num;
+                upper_denom = mid_denom;
+                mid_num += lower_num;
+                mid_denom += lower_denom;
+            } else {
+                lower_num = mid_num;
+                lower_denom = mid_denom;
+                mid_num += upper_num;
+                mid_denom += upper_denom;
+            }
+        }
+        (
+            (Self::narrow(lower_num), Self::narrow(lower_denom)),
+            (Self::narrow(mid_num), Self::narrow(mid_denom)),
+            (Self::narrow(upper_num), Self::narrow(upper_denom)),
+        )
+    }
+
+    /// The exact decimal expansion, with at most `digits` digits after the point and any repeating digits in parentheses, like `0.(142857)` for `1/7`. If the digits neither end nor start repeating within `digits` digits, they are cut off there, followed by `...`.
+    #[must_use]
+    pub fn to_decimal_string(self, digits: usize) -> String {
+        let (num, denom) = self.to_fraction();
+        decimal_string(false, num.into(), denom.into(), digits)
+    }
+
+    /// The path down the Farey tree to `self`, as `L` and `R` steps from the root, like `"LLRR"` for `3/7`. The path to `1` is empty, and `0`, which isn't on the tree, is `"0"`. Every fraction type writes the same path for the same value.
+    #[must_use]
+    pub fn to_path_string(self) -> String {
+        path_string(self.wide(), Self::MAX_DEPTH)
+    }
+
+    /// Follow a path of `L` and `R` steps down from the root, as written by [`to_path_string`](Self::to_path_string).
+    ///
+    /// # Errors
+    /// `FractionError::InvalidSyntax` if the path has anything but `L`s and `R`s, `FractionError::OutOfRange` if it leads past `UFrac::MAX`, `FractionError::PrecisionLoss` if it's otherwise more than `BITS - 1` steps long, and `FractionError::Negative` if it starts with a `-`
+    pub fn from_path_str(path: &str) -> Result<Self, FractionError> {
+        let (is_negative, bits) = parse_path(path, BITS, Self::MAX_DEPTH)?;
+        if is_negative && bits != 0 {
+            return Err(FractionError::Negative);
+        }
+        Ok(Self::from_wide(bits))
+    }
+
+    /// The terms of the continued fraction of `self`, like `[0; 2, 3]` for `3/7`. The path down the tree is the continued fraction run-length encoded, so the terms are read straight off the runs of ones and zeros in the bits. The last term is at least `2` unless `self` is `0` or `1`.
+    pub fn to_continued_fraction(self) -> impl Iterator<Item = u32> {
+        continued_fraction(self.wide(), Self::MAX_DEPTH)
+    }
+
+    /// The nearest representable value to the continued fraction `[a0; a1, ..., an]`, computed exactly from the terms, however many there are and however large. Values greater than `UFrac::MAX` round to `MAX`.
+    ///
+    /// # Errors
+    /// `FractionError::Infinite` if `terms` is empty, which is the convention for infinity, or if a zero term makes the value infinite, as in `[3; 0]`
+    pub fn from_continued_fraction(terms: &[u32]) -> Result<Self, FractionError> {
+        continued_fraction_bits(terms, Self::MAX_DEPTH).map(Self::from_wide)
+    }
+
+    /// The exact value of `num/den`, which needn't be in lowest terms.
+    ///
+    /// # Errors
+    /// `FractionError::Infinite` if `den` is `0`, `FractionError::OutOfRange` if the value is greater than `BITS`, and `FractionError::PrecisionLoss` if it's in range but not exactly representable at this width
+    pub fn from_ratio(
+        num: <Width<BITS> as BitWidth>::Bits,
+        den: <Width<BITS> as BitWidth>::Bits,
+    ) -> Result<Self, FractionError> {
+        let (num, den) = Self::ratio(num, den)?;
+        exact_bits(num, den, BITS, Self::MAX_DEPTH).map(Self::from_wide)
+    }
+
+    /// The nearest representable value to `num/den`, which needn't be in lowest terms. Values greater than `UFrac::MAX` round to `MAX`.
+    ///
+    /// # Errors
+    /// `FractionError::Infinite` if `den` is `0`
+    pub fn nearest_ratio(
+        num: <Width<BITS> as BitWidth>::Bits,
+        den: <Width<BITS> as BitWidth>::Bits,
+    ) -> Result<Self, FractionError> {
+        let (num, den) = Self::ratio(num, den)?;
+        Ok(Self::from_wide(
+            Descent::new(Self::MAX_DEPTH).nearest(num, den),
+        ))
+    }
+
+    /// `num/den` as a ratio to walk down the tree from the root, or `FractionError::Infinite` if `den` is `0`.
+    fn ratio(
+        num: <Width<BITS> as BitWidth>::Bits,
+        den: <Width<BITS> as BitWidth>::Bits,
+    ) -> Result<(U256, U256), FractionError> {
+        if den == <Width<BITS> as BitWidth>::ZERO {
+            Err(FractionError::Infinite)
+        } else {
+            Ok((U256::from_u128(num.into()), U256::from_u128(den.into())))
+        }
+    }
+
+    /// The nearest `f64` to the value. The division is done exactly and rounded once, so this is correct even where the numerator and denominator don't fit in an `f64` on their own.
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        let (num, denom) = self.to_fraction();
+        ratio_to_f64(num.into(), denom.into())
+    }
+
+    /// The nearest `f32` to the value. The division is done exactly and rounded once, so this is correct even where the numerator and denominator don't fit in an `f32` on their own.
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        let (num, denom) = self.to_fraction();
+        ratio_to_f32(num.into(), denom.into())
+    }
+
+    /// Convert to a `UFrac` of another width. Returns `None` if `self` is more than `N - 1` steps down the Farey tree.
+    #[must_use]
+    pub fn try_resize<const N: u32>(self) -> Option<UFrac<N>>
+    where
+        Width<N>: BitWidth,
+    {
+        if self.precision() > N - 1 {
+            None
+        } else {
+            Some(self.resize_lossy())
+        }
+    }
+
+    /// Convert to a `UFrac` of another width. For values with `N - 1` or fewer bits of precision, this conversion is lossless. Deeper values are truncated to their first `N - 1` steps.
+    ///
+    /// If you would like to limit this to a lossless conversion, try `UFrac::try_resize`.
+    #[must_use]
+    pub fn resize_lossy<const N: u32>(self) -> UFrac<N>
+    where
+        Width<N>: BitWidth,
+    {
+        let bits = self.wide();
+        UFrac(<Width<N> as BitWidth>::narrow(if N >= BITS {
+            bits << (N - BITS)
+        } else if self.precision() > N - 1 {
+            // the last step that fits is replaced by the terminating bit
+            (bits >> (BITS - N)) | 1
+        } else {
+            bits >> (BITS - N)
+        }))
+    }
+
+    /// Convert to a `UFrac8`. For values with 7 or fewer bits of precision, this conversion is lossless. For values with 8 or more bits of precision, this conversion truncates excess data.
+    ///
+    /// If you would like to limit this to a lossless conversion, try `UFrac8::try_from`.
+    #[must_use]
+    pub fn to_ufrac8_lossy(self) -> UFrac8 {
+        self.resize_lossy::<8>()
+    }
+
+    /// Convert to a `UFrac16`. For values with 15 or fewer bits of precision, this conversion is lossless. For values with 16 or more bits of precision, this conversion truncates excess data.
+    ///
+    /// If you would like to limit this to a lossless conversion, try `UFrac16::try_from`.
+    #[must_use]
+    pub fn to_ufrac16_lossy(self) -> UFrac16 {
+        self.resize_lossy::<16>()
+    }
+
+    /// Convert to a `UFrac32`. For values with 31 or fewer bits of precision, this conversion is lossless. For values with 32 or more bits of precision, this conversion truncates excess data.
+    ///
+    /// If you would like to limit this to a lossless conversion, try `UFrac32::try_from`.
+    #[must_use]
+    pub fn to_ufrac32_lossy(self) -> UFrac32 {
+        self.resize_lossy::<32>()
+    }
+
+    /// Convert to a `UFrac64`. For values with 63 or fewer bits of precision, this conversion is lossless. For values with 64 or more bits of precision, this conversion truncates excess data.
+    ///
+    /// If you would like to limit this to a lossless conversion, try `UFrac64::try_from`.
+    #[must_use]
+    pub fn to_ufrac64_lossy(self) -> UFrac64 {
+        self.resize_lossy::<64>()
+    }
+
+    /// Convert to an `IFrac8`. For values with 6 or fewer bits of precision, this conversion is lossless. For values with 7 or more bits of precision, this conversion truncates excess data.
+    ///
+    /// If you would like to limit this to a lossless conversion, try `IFrac8::try_from`.
+    #[must_use]
+    pub fn to_ifrac8_lossy(self) -> IFrac8 {
+        if let Ok(frac) = IFrac8::try_from(self) {
+            return frac;
+        }
+        let (magnitude, _) = ufrac_to_ifrac_bits(self.wide(), Self::MAX_DEPTH);
+        #[allow(clippy::cast_possible_truncation)]
+        IFrac8::from_bits((magnitude & ((1 << 6) - 1)) as u8 | (1 << 6))
+    }
+
+    /// The inverse of a `UFrac`. For any nonzero value, `self.invert().invert()` is guaranteed to be equal to `self`.
+    ///
+    /// # Panics
+    /// If `self` is equal to `0`
+    #[must_use]
+    pub fn invert(self) -> Self {
+        assert!(self != Self::ZERO, "Can't invert `0/1`");
+        self.invert_unchecked()
+    }
+
+    /// The inverse of a `UFrac`. If `self` is equal to `0`, returns `None`.
+    ///
+    /// For any nonzero value, `self.invert().unwrap().invert().unwrap()` is guaranteed to be equal to `self`.
+    #[must_use]
+    pub fn try_invert(self) -> Option<Self> {
+        if self == Self::ZERO {
+            None
+        } else {
+            Some(self.invert_unchecked())
+        }
+    }
+
+    /// The inverse of a `UFrac`. If `self` is equal to `0`, returns `0`.
+    #[must_use]
+    pub fn invert_unchecked(self) -> Self {
+        let bits = self.wide();
+        let all = Self::MAX.wide();
+        let mask = all
+            .checked_shl(bits.trailing_zeros() + 1)
+            .map_or(0, |mask| mask & all);
+        Self::from_wide(bits ^ mask)
+    }
+
+    /// Construct a `UFrac` from a bit pattern
+    #[must_use]
+    pub const fn from_bits(bits: <Width<BITS> as BitWidth>::Bits) -> Self {
+        Self(bits)
+    }
+
+    /// Get the bit pattern out of a `UFrac`
+    #[must_use]
+    pub const fn to_bits(self) -> <Width<BITS> as BitWidth>::Bits {
+        self.0
+    }
+
+    /// Get the precision of a value. This will be a value from 0 to `BITS - 1` representing how many steps down the Farey tree the fraction is.
+    /// If `self` is equal to `0` or `1`, this function will return `0`.
+    #[must_use]
+    pub fn precision(self) -> u32 {
+        Self::MAX_DEPTH.saturating_sub(self.wide().trailing_zeros())
+    }
+
+    #[must_use]
+    /// Get the fraction's parent node on the Farey tree. Returns `None` if `self` is 0 or 1.
+    pub fn parent(self) -> Option<Self> {
+        let bits = self.wide();
+        let trailing_zeroes = bits.trailing_zeros();
+        if trailing_zeroes >= Self::MAX_DEPTH {
+            None
+        } else {
+            // drop the last step and move the terminating bit up into its place
+            Some(Self::from_wide(
+                (bits & !(0b11 << trailing_zeroes)) | (1 << (trailing_zeroes + 1)),
+            ))
+        }
+    }
+
+    /// Iterate over `self` and its ancestors on the Farey tree, walking up with [`parent`](Self::parent) to `1`, which comes last. `0` isn't on the tree, so its iterator is empty.
+    pub fn ancestors(self) -> impl Iterator<Item = Self> {
+        std::iter::successors((self != Self::ZERO).then_some(self), |frac| frac.parent())
+    }
+
+    /// Iterate over the steps down the Farey tree from `1` to `self`, each as the node it reaches and the direction it goes, so `3/7` gives `1/2`, `1/3`, `2/5` and `3/7`, going left, left, right and right. The path to `1` and to `0` has no steps.
+    pub fn path_from_root(self) -> impl Iterator<Item = (Self, Direction)> {
+        let bits = self.wide();
+        (0..self.precision()).map(move |depth| {
+            // the step's bit, and the node with the path cut off after it
+            let shift = Self::MAX_DEPTH - depth;
+            let node = (bits & (u128::MAX << shift)) | (1 << (shift - 1));
+            (
+                Self::from_wide(node),
+                Direction::from_bit(bits >> shift & 1 == 1),
+            )
+        })
+    }
+
+    #[must_use]
+    /// Get the fraction's left child node on the Farey tree. Returns `None` if called on `0` or a leaf.
+    pub fn left_child(self) -> Option<Self> {
+        self.children().map(|(left, _)| left)
+    }
+
+    #[must_use]
+    /// Get the fraction's right child node on the Farey tree. Returns `None` if called on `0` or a leaf.
+    pub fn right_child(self) -> Option<Self> {
+        self.children().map(|(_, right)| right)
+    }
+
+    #[must_use]
+    /// Get the fraction's child nodes on the Farey tree. Returns `None` if called on `0` or a leaf.
+    ///
+    /// Equivalent to `(self.left_child()?,self.right_child()?)`
+    pub fn children(self) -> Option<(Self, Self)> {
+        if self == Self::ZERO || self.is_leaf() {
+            None
+        } else {
+            let bits = self.wide();
+            let right_child = bits | (1 << (bits.trailing_zeros() - 1));
+            Some((
+                Self::from_wide(right_child & !(1 << bits.trailing_zeros())),
+                Self::from_wide(right_child),
+            ))
+        }
+    }
+
+    #[must_use]
+    /// Get the other child of the fraction's parent node on the Farey tree. Returns `None` if called on `0` or `1`.
+    pub fn sibling(self) -> Option<Self> {
+        if self == Self::ZERO || self == Self::ONE {
+            None
+        } else {
+            Some(self.sibling_unchecked())
+        }
+    }
+
+    #[must_use]
+    /// Get the other child of the fraction's parent node on the Farey tree. Behavior is undefined if called on `0` or `1`.
+    pub fn sibling_unchecked(self) -> Self {
+        let bits = self.wide();
+        Self::from_wide(bits ^ (1 << (bits.trailing_zeros() + 1)))
+    }
+
+    #[must_use]
+    /// Check if the value has the highest possible precision for its width. If `true`, `left_child()` and `right_child()` will both return `None`.
+    pub fn is_leaf(self) -> bool {
+        self.wide() & 1 != 0
+    }
+
+    #[must_use]
+    /// Get the next larger value of this width, like `f64::next_up`. Returns `None` if called on `MAX`.
+    ///
+    /// Every bit pattern is a node, the lowest set bit being its terminator, and the patterns sort in value order, so this is just the next pattern up.
+    pub fn next_up(self) -> Option<Self> {
+        (self != Self::MAX).then(|| Self::from_wide(self.wide() + 1))
+    }
+
+    #[must_use]
+    /// Get the next smaller value of this width, like `f64::next_down`. Returns `None` if called on `0`.
+    pub fn next_down(self) -> Option<Self> {
+        (self != Self::ZERO).then(|| Self::from_wide(self.wide() - 1))
+    }
+
+    #[must_use]
+    /// Get the simplest fraction strictly between `a` and `b`, in either order: the shallowest node on the Farey tree between them, which has the smallest numerator and denominator of any fraction there. Returns `None` if no fraction between them fits in `BITS` bits.
+    pub fn simplest_between(a: Self, b: Self) -> Option<Self> {
+        let (lower, upper) = (a.min(b).wide(), a.max(b).wide());
+        (upper - lower >= 2).then(|| Self::from_wide(Self::shallowest_in(lower + 1, upper - 1)))
+    }
+
+    #[must_use]
+    /// Get the simplest fraction from `a` to `b` inclusive, in either order, as [`simplest_between`](Self::simplest_between) does. `0` is simpler than any node, so it's the result whenever it's one of the ends.
+    pub fn simplest_between_inclusive(a: Self, b: Self) -> Self {
+        let (lower, upper) = (a.min(b).wide(), a.max(b).wide());
+        if lower == 0 {
+            Self::ZERO
+        } else {
+            Self::from_wide(Self::shallowest_in(lower, upper))
+        }
+    }
+
+    #[must_use]
+    /// Get the deepest node on the Farey tree that both `a` and `b` lie under, each counting as under itself, so the common ancestor of `3/7` and `2/5` is `2/5`. Returns `None` if either is `0`, which isn't on the tree.
+    pub fn common_ancestor(a: Self, b: Self) -> Option<Self> {
+        if a == Self::ZERO || b == Self::ZERO {
+            None
+        } else {
+            let (ancestor, _) = common_ancestor(a.wide(), b.wide(), Self::MAX_DEPTH);
+            Some(Self::from_wide(ancestor))
+        }
+    }
+
+    #[must_use]
+    /// Get how many parent and child steps apart `a` and `b` are on the Farey tree: up from one to their [`common_ancestor`](Self::common_ancestor) and down to the other. Returns `None` if either is `0`, which isn't on the tree.
+    pub fn tree_distance(a: Self, b: Self) -> Option<u32> {
+        if a == Self::ZERO || b == Self::ZERO {
+            None
+        } else {
+            let (_, distance) = common_ancestor(a.wide(), b.wide(), Self::MAX_DEPTH);
+            Some(distance)
+        }
+    }
+
+    /// The bit pattern from `lower` to `upper` inclusive with the most trailing zeros, which is the shallowest node among them: their common prefix, followed by a terminating `1` where they part ways, unless `lower` is the prefix itself.
+    const fn shallowest_in(lower: u128, upper: u128) -> u128 {
+        if lower == upper {
+            return lower;
+        }
+        let split = u128::BITS - 1 - (lower ^ upper).leading_zeros();
+        if lower & (u128::MAX >> (u128::BITS - 1 - split)) == 0 {
+            lower
+        } else {
+            upper & !((1 << split) - 1)
+        }
+    }
+
+    /// The bit pattern, widened for arithmetic.
+    fn wide(self) -> u128 {
+        self.0.into()
+    }
+
+    /// Construct a `UFrac` from a widened bit pattern that fits in `BITS` bits.
+    fn from_wide(bits: u128) -> Self {
+        Self(Self::narrow(bits))
+    }
+
+    fn narrow(bits: u128) -> <Width<BITS> as BitWidth>::Bits {
+        <Width<BITS> as BitWidth>::narrow(bits)
+    }
+
+    /// The integer `value`, or `FractionError::OutOfRange` if it's greater than `BITS`.
+    pub(super) fn from_integer(value: u128) -> Result<Self, FractionError> {
+        if value == 0 {
+            Ok(Self::ZERO)
+        } else if value <= u128::from(BITS) {
+            #[allow(clippy::cast_possible_truncation)]
+            Ok(Self::from_wide(
+                (Self::MAX.wide() << (BITS - value as u32)) & Self::MAX.wide(),
+            ))
+        } else {
+            Err(FractionError::OutOfRange { max: BITS })
+        }
+    }
+
+    /// Create a `UFrac` approximating an IEEE half-precision float, given as its bit pattern, comparing with it in half precision.
+    ///
+    /// # Errors
+    /// If passed a negative, infinite or `NaN` value
+    pub fn from_f16_bits(bits: u16) -> Result<Self, FractionError> {
+        Self::from_float(F16::from_bits(bits))
+    }
+
+    /// Create a `UFrac` from a float, rounding it as `mode` says. Values greater than `UFrac::MAX` round down to `MAX`.
+    ///
+    /// # Errors
+    /// If passed a negative, infinite or `NaN` value, and `FractionError::OutOfRange` when rounding up a value greater than `MAX`
+    pub fn from_f64_rounded(value: f64, mode: RoundingMode) -> Result<Self, FractionError> {
+        Self::check_float(value)?;
+        if value == 0.0 {
+            return Ok(Self::ZERO);
+        }
+        round(value, Self::MAX_DEPTH, mode)
+            .map(Self::from_wide)
+            .ok_or(FractionError::OutOfRange { max: BITS })
+    }
+
+    /// Approximate a float by walking down the tree in its own precision.
+    fn from_float<F: Float>(value: F) -> Result<Self, FractionError> {
+        let float = value.to_f64();
+        Self::check_float(float)?;
+        if float == 0.0 {
+            Ok(Self::ZERO)
+        } else {
+            Ok(Self::from_wide(descend(value, Self::MAX_DEPTH)))
+        }
+    }
+
+    /// Check that a float is finite and not negative; `-0.0` is fine.
+    fn check_float(value: f64) -> Result<(), FractionError> {
+        FractionError::check_finite(value)?;
+        if value < 0.0 {
+            Err(FractionError::Negative)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// This value as an input to bit-serial arithmetic.
+    fn operand(self) -> Operand {
+        Operand::from_ufrac_bits(self.wide(), Self::MAX_DEPTH)
+    }
+
+    /// Evaluate `function` at `self` and `rhs` down to this type's precision, leaving the rest of the result exact.
+    fn evaluate(self, function: Bihomographic, rhs: Self) -> (Descent, U256, U256) {
+        function.evaluate(self.operand(), rhs.operand(), Self::MAX_DEPTH)
+    }
+
+    /// Add two `UFrac`s, rounding to the nearest representable value. Returns `None` if the sum is greater than `UFrac::MAX`.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (descent, num, denom) = self.evaluate(Bihomographic::SUM, rhs);
+        descent.checked(num, denom).map(Self::from_wide)
+    }
+
+    /// Subtract two `UFrac`s, rounding to the nearest representable value. Returns `None` if the difference is negative.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        if self < rhs {
+            return None;
+        }
+        let (descent, num, denom) = self.evaluate(Bihomographic::DIFFERENCE, rhs);
+        Some(Self::from_wide(descent.nearest(num, denom)))
+    }
+
+    /// Multiply two `UFrac`s, rounding to the nearest representable value. Returns `None` if the product is greater than `UFrac::MAX`.
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (descent, num, denom) = self.evaluate(Bihomographic::PRODUCT, rhs);
+        descent.checked(num, denom).map(Self::from_wide)
+    }
+
+    /// Divide two `UFrac`s, rounding to the nearest representable value. Returns `None` if `rhs` is `0` or the quotient is greater than `UFrac::MAX`.
+    #[must_use]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.try_invert()?)
+    }
+
+    /// Add two `UFrac`s, rounding to the nearest representable value and clamping at `UFrac::MAX`. This is the same as `self + rhs`.
+    #[must_use]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    /// Subtract two `UFrac`s, rounding to the nearest representable value and clamping at `UFrac::ZERO`. This is the same as `self - rhs`.
+    #[must_use]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    /// Multiply two `UFrac`s, rounding to the nearest representable value and clamping at `UFrac::MAX`. This is the same as `self * rhs`.
+    #[must_use]
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    /// Divide two `UFrac`s, rounding to the nearest representable value and clamping at `UFrac::MAX`. This is the same as `self / rhs`.
+    ///
+    /// # Panics
+    /// If `rhs` is equal to `0`
+    #[must_use]
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    /// Add two `UFrac`s. Returns `None` if the sum isn't exactly representable at this width.
+    #[must_use]
+    pub fn exact_add(self, rhs: Self) -> Option<Self> {
+        let (descent, num, denom) = self.evaluate(Bihomographic::SUM, rhs);
+        descent.exact(num, denom).map(Self::from_wide)
+    }
+
+    /// Subtract two `UFrac`s. Returns `None` if the difference is negative or isn't exactly representable at this width.
+    #[must_use]
+    pub fn exact_sub(self, rhs: Self) -> Option<Self> {
+        if self < rhs {
+            return None;
+        }
+        let (descent, num, denom) = self.evaluate(Bihomographic::DIFFERENCE, rhs);
+        descent.exact(num, denom).map(Self::from_wide)
+    }
+
+    /// Multiply two `UFrac`s. Returns `None` if the product isn't exactly representable at this width.
+    #[must_use]
+    pub fn exact_mul(self, rhs: Self) -> Option<Self> {
+        let (descent, num, denom) = self.evaluate(Bihomographic::PRODUCT, rhs);
+        descent.exact(num, denom).map(Self::from_wide)
+    }
+
+    /// Divide two `UFrac`s. Returns `None` if `rhs` is `0` or the quotient isn't exactly representable at this width.
+    #[must_use]
+    pub fn exact_div(self, rhs: Self) -> Option<Self> {
+        self.exact_mul(rhs.try_invert()?)
+    }
+}
+
+impl<const BITS: u32> TryFrom<f64> for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    type Error = FractionError;
+    /// Try to create a `UFrac` approximating a float. Fails if passed a negative, infinite or `NaN` value.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::from_float(value)
+    }
+}
+
+impl<const BITS: u32> TryFrom<f32> for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    type Error = FractionError;
+    /// Try to create a `UFrac` approximating a float, comparing with it in single precision. Fails if passed a negative, infinite or `NaN` value.
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::from_float(value)
+    }
+}
+
+impl<const BITS: u32> TryFrom<IFrac8> for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    type Error = FractionError;
+    /// Try to convert an `IFrac8` into a `UFrac`. Fails if passed a negative value, or one greater than `BITS` or too deep in the Farey tree for this width; every nonnegative `IFrac8` fits in 7 bits or more.
+    fn try_from(value: IFrac8) -> Result<Self, Self::Error> {
+        if value.is_negative() && value.abs() != IFrac8::ZERO {
+            return Err(FractionError::Negative);
+        }
+        if ifrac_exceeds(value.abs().to_bits().into(), BITS) {
+            return Err(FractionError::OutOfRange { max: BITS });
+        }
+        if u32::from(value.precision()) > Self::MAX_DEPTH {
+            return Err(FractionError::PrecisionLoss {
+                bits_needed: u128::from(value.precision()) + 1,
+            });
+        }
+        Ok(Self::from_wide(ifrac_to_ufrac_bits(
+            u128::from(value.abs().to_bits()),
+            Self::MAX_DEPTH,
+        )))
+    }
+}
+
+impl<const BITS: u32> FareyFraction for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    type Bits = <Width<BITS> as BitWidth>::Bits;
+    type Int = <Width<BITS> as BitWidth>::Bits;
+
+    const MAX_PRECISION: u32 = BITS - 1;
+    const ZERO: Self = Self::ZERO;
+    const MIN: Self = Self::MIN;
+    const ONE: Self = Self::ONE;
+    const MAX: Self = Self::MAX;
+
+    fn to_fraction(self) -> (Self::Int, Self::Int) {
+        self.to_fraction()
+    }
+    fn from_bits(bits: Self::Bits) -> Self {
+        Self::from_bits(bits)
+    }
+    fn to_bits(self) -> Self::Bits {
+        self.to_bits()
+    }
+    fn precision(self) -> u32 {
+        self.precision()
+    }
+    fn parent(self) -> Option<Self> {
+        self.parent()
+    }
+    fn left_child(self) -> Option<Self> {
+        self.left_child()
+    }
+    fn right_child(self) -> Option<Self> {
+        self.right_child()
+    }
+    fn children(self) -> Option<(Self, Self)> {
+        self.children()
+    }
+    fn sibling(self) -> Option<Self> {
+        self.sibling()
+    }
+    fn is_leaf(self) -> bool {
+        self.is_leaf()
+    }
+    fn invert(self) -> Self {
+        self.invert()
+    }
+    fn try_invert(self) -> Option<Self> {
+        self.try_invert()
+    }
+}
+
+impl<const BITS: u32> Add for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    type Output = Self;
+    /// Add two `UFrac`s exactly, then round to the nearest representable value. Sums greater than `UFrac::MAX` round to `MAX`.
+    fn add(self, rhs: Self) -> Self::Output {
+        let (descent, num, denom) = self.evaluate(Bihomographic::SUM, rhs);
+        Self::from_wide(descent.nearest(num, denom))
+    }
+}
+
+impl<const BITS: u32> AddAssign for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const BITS: u32> Sub for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    type Output = Self;
+    /// Subtract two `UFrac`s exactly, then round to the nearest representable value. Differences less than zero saturate to `UFrac::ZERO`.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).unwrap_or(Self::ZERO)
+    }
+}
+
+impl<const BITS: u32> SubAssign for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const BITS: u32> Mul for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    type Output = Self;
+    /// Multiply two `UFrac`s exactly, then round to the nearest representable value. Products greater than `UFrac::MAX` round to `MAX`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (descent, num, denom) = self.evaluate(Bihomographic::PRODUCT, rhs);
+        Self::from_wide(descent.nearest(num, denom))
+    }
+}
+
+impl<const BITS: u32> MulAssign for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const BITS: u32> Div for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    type Output = Self;
+    /// Divide two `UFrac`s exactly, then round to the nearest representable value. Since the inverse of a `UFrac` is always exact, this is the same as multiplying by `rhs.invert()`.
+    ///
+    /// # Panics
+    /// If `rhs` is equal to `0`
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.invert()
+    }
+}
+
+impl<const BITS: u32> DivAssign for UFrac<BITS>
+where
+    Width<BITS>: BitWidth,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+/// Lossless widening and checked narrowing between the named widths.
+macro_rules! resize_conversions {
+    ($narrow:literal => $($wide:literal)*) => {$(
+        impl From<UFrac<$narrow>> for UFrac<$wide> {
+            fn from(value: UFrac<$narrow>) -> Self {
+                value.resize_lossy()
+            }
+        }
+
+        impl TryFrom<UFrac<$wide>> for UFrac<$narrow> {
+            type Error = FractionError;
+            #[doc = concat!("Try to fit a `UFrac", $wide, "` into a `UFrac", $narrow, "`. Fails if passed a value greater than ", $narrow, ", or one with ", $narrow, " or more bits of precision. If you would like to truncate the value instead, try `UFrac::resize_lossy`.")]
+            fn try_from(value: UFrac<$wide>) -> Result<Self, Self::Error> {
+                if ufrac_exceeds(value.wide(), $wide - 1, $narrow) {
+                    return Err(FractionError::OutOfRange { max: $narrow });
+                }
+                value.try_resize().ok_or(FractionError::PrecisionLoss {
+                    bits_needed: u128::from(value.precision()) + 1,
+                })
+            }
+        }
+    )*};
+}
+
+resize_conversions!(8 => 16 32 64 128);
+resize_conversions!(16 => 32 64 128);
+resize_conversions!(32 => 64 128);
+resize_conversions!(64 => 128);
+
+/// Conversions into `f64` for the widths where every value gets its own `f64`, so the value can be recovered from it.
+macro_rules! float_conversions {
+    ($($bits:literal)*) => {$(
+        impl From<UFrac<$bits>> for f64 {
+            #[doc = concat!("The nearest `f64` to a `UFrac", $bits, "`. No two `UFrac", $bits, "`s round to the same `f64`.")]
+            fn from(value: UFrac<$bits>) -> Self {
+                value.to_f64()
+            }
+        }
+    )*};
+}
+
+float_conversions!(8 16 32);
+
+macro_rules! bit_formats {
+    ($($format:ident)*) => {$(
+        impl<const BITS: u32> $format for UFrac<BITS>
+        where
+            Width<BITS>: BitWidth,
+        {
+            /// Writes the bit pattern, the same way as for the unsigned integer that holds it.
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                $format::fmt(&self.0, f)
+            }
+        }
+    )*};
+}
+
+bit_formats!(Binary Octal LowerHex UpperHex);
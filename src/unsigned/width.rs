@@ -0,0 +1,86 @@
+use std::{
+    fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex},
+    hash::Hash,
+};
+
+use super::UFrac;
+use crate::FractionError;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A bit width, as a type. [`UFrac<BITS>`](UFrac) exists for every `Width<BITS>` that implements [`BitWidth`].
+pub struct Width<const BITS: u32>;
+
+/// The bit widths a [`UFrac`] can have, from 2 to 128 bits. This trait is sealed.
+pub trait BitWidth: sealed::Sealed {
+    /// The smallest unsigned integer with room for the bit pattern
+    type Bits: Copy
+        + Eq
+        + Ord
+        + Hash
+        + Default
+        + Debug
+        + Display
+        + Binary
+        + Octal
+        + LowerHex
+        + UpperHex
+        + Into<u128>;
+
+    /// The bit pattern of `0`
+    const ZERO: Self::Bits;
+    /// The bit pattern of the lowest non-zero value
+    const MIN: Self::Bits;
+    /// The bit pattern of `1`
+    const ONE: Self::Bits;
+    /// The bit pattern of the highest value
+    const MAX: Self::Bits;
+
+    /// Narrow a bit pattern that is known to fit.
+    fn narrow(bits: u128) -> Self::Bits;
+}
+
+macro_rules! bit_widths {
+    ($bits:ty: $($width:literal)*) => {$(
+        impl sealed::Sealed for Width<$width> {}
+
+        #[allow(clippy::cast_possible_truncation)]
+        impl BitWidth for Width<$width> {
+            type Bits = $bits;
+
+            const ZERO: $bits = 0;
+            const MIN: $bits = 1;
+            const ONE: $bits = 1 << ($width - 1);
+            const MAX: $bits = (u128::MAX >> (128 - $width)) as $bits;
+
+            fn narrow(bits: u128) -> $bits {
+                bits as $bits
+            }
+        }
+
+        #[allow(clippy::useless_conversion)]
+        impl TryFrom<$bits> for UFrac<$width> {
+            type Error = FractionError;
+            #[doc = concat!("Try to create an integer `UFrac<", $width, ">`. Fails if passed a value greater than ", $width, ".")]
+            fn try_from(value: $bits) -> Result<Self, Self::Error> {
+                Self::from_integer(value.into())
+            }
+        }
+
+        impl TryFrom<($bits, $bits)> for UFrac<$width> {
+            type Error = FractionError;
+            #[doc = concat!("Try to create a `UFrac<", $width, ">` from a numerator and denominator, which needn't be in lowest terms. Fails if the denominator is `0`, the value is greater than ", $width, ", or it isn't exactly representable in ", $width, " bits.")]
+            fn try_from((num, den): ($bits, $bits)) -> Result<Self, Self::Error> {
+                Self::from_ratio(num, den)
+            }
+        }
+    )*};
+}
+
+bit_widths!(u8: 2 3 4 5 6 7 8);
+bit_widths!(u16: 9 10 11 12 13 14 15 16);
+bit_widths!(u32: 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32);
+bit_widths!(u64: 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64);
+bit_widths!(u128: 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95 96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111 112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127 128);
@@ -0,0 +1,195 @@
+use std::cmp::Ordering;
+
+/// A 256-bit unsigned integer, just wide enough for exact arithmetic on two `UFrac128`s.
+///
+/// [`Bihomographic`](crate::gosper::Bihomographic) also uses it for signed coefficients in two's complement, through the `wrapping_` methods and [`U256::is_negative`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct U256 {
+    high: u128,
+    low: u128,
+}
+
+impl U256 {
+    pub const ZERO: Self = Self::from_u128(0);
+    pub const ONE: Self = Self::from_u128(1);
+
+    pub const fn from_u128(value: u128) -> Self {
+        Self {
+            high: 0,
+            low: value,
+        }
+    }
+
+    /// The two's complement of a signed value.
+    pub const fn from_i128(value: i128) -> Self {
+        Self {
+            high: if value < 0 { u128::MAX } else { 0 },
+            #[allow(clippy::cast_sign_loss)]
+            low: value as u128,
+        }
+    }
+
+    /// The value as a `u128`, or `None` if it doesn't fit.
+    pub const fn to_u128(self) -> Option<u128> {
+        if self.high == 0 {
+            Some(self.low)
+        } else {
+            None
+        }
+    }
+
+    pub const fn is_zero(self) -> bool {
+        self.high == 0 && self.low == 0
+    }
+
+    /// Whether the value is negative when read as two's complement.
+    pub const fn is_negative(self) -> bool {
+        self.high >> 127 == 1
+    }
+
+    pub const fn eq(self, rhs: Self) -> bool {
+        self.high == rhs.high && self.low == rhs.low
+    }
+
+    pub const fn cmp(self, rhs: Self) -> Ordering {
+        if self.high < rhs.high {
+            Ordering::Less
+        } else if self.high > rhs.high {
+            Ordering::Greater
+        } else if self.low < rhs.low {
+            Ordering::Less
+        } else if self.low > rhs.low {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        let (low, carry) = self.low.overflowing_add(rhs.low);
+        Self {
+            high: self.high.wrapping_add(rhs.high).wrapping_add(carry as u128),
+            low,
+        }
+    }
+
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        let (low, borrow) = self.low.overflowing_sub(rhs.low);
+        Self {
+            high: self
+                .high
+                .wrapping_sub(rhs.high)
+                .wrapping_sub(borrow as u128),
+            low,
+        }
+    }
+
+    /// Add, or `None` if the sum doesn't fit.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (low, carry) = self.low.overflowing_add(rhs.low);
+        match self.high.checked_add(rhs.high) {
+            Some(high) => match high.checked_add(carry as u128) {
+                Some(high) => Some(Self { high, low }),
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Multiply, or `None` if the operands have more than 256 bits between them, so that the product might not fit.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        if self.bit_len() + rhs.bit_len() > 256 {
+            return None;
+        }
+        let mut product = Self::ZERO;
+        let mut index = 0;
+        while index < rhs.bit_len() {
+            if rhs.bit(index) {
+                product = product.wrapping_add(self.shl(index));
+            }
+            index += 1;
+        }
+        Some(product)
+    }
+
+    /// Multiply by a small factor. The product must fit.
+    pub const fn mul_u32(self, rhs: u32) -> Self {
+        let mut product = Self::ZERO;
+        let mut addend = self;
+        let mut rhs = rhs;
+        while rhs != 0 {
+            if rhs & 1 == 1 {
+                product = product.wrapping_add(addend);
+            }
+            addend = addend.shl1();
+            rhs >>= 1;
+        }
+        product
+    }
+
+    /// Divide, returning the quotient and the remainder. `rhs` must not be zero.
+    pub const fn div_rem(self, rhs: Self) -> (Self, Self) {
+        if self.high == 0 && rhs.high == 0 {
+            return (
+                Self::from_u128(self.low / rhs.low),
+                Self::from_u128(self.low % rhs.low),
+            );
+        }
+        // schoolbook long division, one bit at a time
+        let mut quotient = Self::ZERO;
+        let mut remainder = Self::ZERO;
+        let mut bit = 256;
+        while bit > 0 {
+            bit -= 1;
+            remainder = remainder.shl1();
+            remainder.low |= self.bit(bit) as u128;
+            quotient = quotient.shl1();
+            if !matches!(remainder.cmp(rhs), Ordering::Less) {
+                remainder = remainder.wrapping_sub(rhs);
+                quotient.low |= 1;
+            }
+        }
+        (quotient, remainder)
+    }
+
+    /// Shift left by fewer than 256 bits, dropping whatever is shifted out.
+    pub const fn shl(self, shift: u32) -> Self {
+        if shift == 0 {
+            self
+        } else if shift >= 128 {
+            Self {
+                high: self.low << (shift - 128),
+                low: 0,
+            }
+        } else {
+            Self {
+                high: (self.high << shift) | (self.low >> (128 - shift)),
+                low: self.low << shift,
+            }
+        }
+    }
+
+    /// The number of bits needed to hold the value; `0` for zero.
+    pub const fn bit_len(self) -> u32 {
+        if self.high == 0 {
+            128 - self.low.leading_zeros()
+        } else {
+            256 - self.high.leading_zeros()
+        }
+    }
+
+    const fn shl1(self) -> Self {
+        Self {
+            high: (self.high << 1) | (self.low >> 127),
+            low: self.low << 1,
+        }
+    }
+
+    const fn bit(self, index: u32) -> bool {
+        if index >= 128 {
+            (self.high >> (index - 128)) & 1 == 1
+        } else {
+            (self.low >> index) & 1 == 1
+        }
+    }
+}